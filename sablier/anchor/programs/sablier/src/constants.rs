@@ -3,3 +3,34 @@ pub const ANCHOR_DISCRIMINATOR: usize = 8;
 
 /// Public key representing the team authority.
 pub const TEAM_PUB_KEY: &str = "7aPQWrJu4Qg9zAQVbNFkaZAkQupmChirCmQqEwF4dAad";
+
+/// Maximum number of segments a lockup dynamic stream can hold.
+pub const MAX_SEGMENT_COUNT: usize = 10;
+
+/// Fixed-point scale of segment exponents (1e18 represents an exponent of 1).
+pub const SEGMENT_EXPONENT_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Maximum number of tranches a lockup tranched stream can hold.
+pub const MAX_TRANCHE_COUNT: usize = 48;
+
+/// Maximum protocol fee that can be charged on a deposit, in basis points (10%).
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+
+/// Number of basis points in 100%.
+pub const BPS_SCALE: u64 = 10_000;
+
+/// Maximum broker fee that can be charged on a deposit, in basis points (10%).
+pub const MAX_BROKER_FEE_BPS: u16 = 1_000;
+
+/// Maximum duration of a campaign's stream template, in seconds (100 years).
+pub const MAX_STREAM_TEMPLATE_DURATION: i64 = 100 * 365 * 24 * 60 * 60;
+
+/// Maximum rate per second of a flow stream, low enough for its debt to keep fitting in a `u64`
+/// after 100 years of accrual.
+pub const MAX_FLOW_RATE_PER_SECOND: u64 = u64::MAX / (100 * 365 * 24 * 60 * 60);
+
+/// Maximum length of a campaign's name in bytes, as it seeds the campaign's address.
+pub const MAX_CAMPAIGN_NAME_LENGTH: usize = 32;
+
+/// Symbol of the NFTs representing lockup linear streams.
+pub const LOCKUP_LINEAR_NFT_SYMBOL: &str = "SAB-LL";
@@ -9,9 +9,18 @@ pub mod Error {
             #[msg("Stream is already canceled")]
             AlreadyCanceled,
 
+            #[msg("Stream is paused")]
+            AlreadyPaused,
+
+            #[msg("Arithmetic overflow while computing stream amounts")]
+            ArithmeticOverflow,
+
             #[msg("The cliff time has not passed yet")]
             CliffNotEnded,
 
+            #[msg("Batch must contain at least one stream")]
+            EmptyBatch,
+
             #[msg("Stream is empty")]
             EmptyStream,
 
@@ -21,29 +30,149 @@ pub mod Error {
             #[msg("Amount must be greater than 0")]
             InvalidAmount,
 
+            #[msg("Remaining accounts do not match the batch entries")]
+            InvalidBatchAccounts,
+
+            #[msg("Broker fee exceeds the maximum allowed")]
+            InvalidBrokerFee,
+
             #[msg("Cliff time must be between start and end time")]
             InvalidCliffTime,
 
+            #[msg("A cliff unlock requires a cliff time after the start time")]
+            InvalidCliffUnlock,
+
             #[msg("End time must be after start time")]
             InvalidEndTime,
 
+            #[msg("Hook program account does not match the registered Stream Hook")]
+            InvalidHookProgram,
+
+            #[msg("Account is not a legacy lockup stream")]
+            InvalidLegacyAccount,
+
+            #[msg("Segment amounts must add up to the deposited amount")]
+            InvalidSegmentAmounts,
+
+            #[msg("Segment count must be between 1 and the maximum allowed")]
+            InvalidSegmentCount,
+
+            #[msg("Segment milestones must be strictly increasing and after the start time")]
+            InvalidSegmentMilestones,
+
             #[msg("Start time must not be in the past")]
             InvalidStartTime,
 
+            #[msg("Tranche amounts must add up to the deposited amount")]
+            InvalidTrancheAmounts,
+
+            #[msg("Tranche count must be between 1 and the maximum allowed")]
+            InvalidTrancheCount,
+
+            #[msg("Tranche timestamps must be strictly increasing and after the start time")]
+            InvalidTrancheTimestamps,
+
             #[msg("Stream Id is not in the correct format")]
             InvalidStreamIdFormat,
 
+            #[msg("Unlock amounts must not exceed the deposited amount")]
+            InvalidUnlockAmounts,
+
+            #[msg("A broker token account is required to charge a broker fee")]
+            MissingBrokerTokenAccount,
+
+            #[msg("Stream has no approved operator")]
+            MissingOperator,
+
+            #[msg("Stream has no linearly streamed amount to extend the end time by")]
+            NoStreamableAmount,
+
             #[msg("Stream is not cancelable")]
             NotCancelable,
 
             #[msg("Stream is not cancelable after the end time has passed")]
             NotCancelablePastEndTime,
 
+            #[msg("Stream still holds funds that have not been withdrawn or refunded")]
+            NotDepleted,
+
+            #[msg("Stream is not paused")]
+            NotPaused,
+
             #[msg("Stream cancelability is not renounceable after the end time has passed")]
             NotRenounceablePastEndTime,
 
+            #[msg("Stream cannot be topped up after the end time has passed")]
+            NotToppablePastEndTime,
+
+            #[msg("Stream is not transferable")]
+            NotTransferable,
+
+            #[msg("There is nothing to withdraw from the Stream")]
+            NothingToWithdraw,
+
             #[msg("Stream has not started yet")]
             StreamNotStarted,
+
+            #[msg("Token mint has a transfer fee, which this stream type does not support")]
+            UnsupportedTransferFee,
+
+            #[msg("Token mint has an extension that streams do not support")]
+            UnsupportedMintExtension,
+        }
+
+        #[error_code]
+        pub enum Campaign {
+            #[msg("Campaign has expired")]
+            Expired,
+
+            #[msg("Expiry time must be in the future")]
+            InvalidExpiryTime,
+
+            #[msg("Merkle proof is invalid")]
+            InvalidMerkleProof,
+
+            #[msg("Campaign name must be at most 32 bytes long")]
+            InvalidName,
+
+            #[msg("Stream template durations are invalid")]
+            InvalidStreamTemplate,
+
+            #[msg("Campaign has not expired yet")]
+            NotExpired,
+        }
+
+        #[error_code]
+        pub enum Flow {
+            #[msg("Flow Stream is already paused")]
+            AlreadyPaused,
+
+            #[msg("Flow Stream is already voided")]
+            AlreadyVoided,
+
+            #[msg("Rate per second exceeds the maximum allowed")]
+            ExceedsMaxRatePerSecond,
+
+            #[msg("Refund amount exceeds the refundable balance")]
+            ExceedsRefundable,
+
+            #[msg("Withdrawal amount exceeds the covered debt")]
+            ExceedsWithdrawable,
+
+            #[msg("Rate per second must be greater than 0 and differ from the current one")]
+            InvalidRatePerSecond,
+
+            #[msg("Flow Stream is not paused")]
+            NotPaused,
+        }
+
+        #[error_code]
+        pub enum Config {
+            #[msg("Protocol fee exceeds the maximum allowed")]
+            InvalidProtocolFee,
+
+            #[msg("There are no protocol fees to collect")]
+            NothingToCollect,
         }
     }
 
@@ -55,13 +184,52 @@ pub mod Error {
             #[msg("Only the Stream's Creator can cancel the Stream")]
             UnauthorizedCancel,
 
+            #[msg("Only the Stream's Creator can close the Stream")]
+            UnauthorizedClose,
+
+            #[msg("Only the Stream's Recipient can approve or revoke an operator")]
+            UnauthorizedOperatorApproval,
+
+            #[msg("Only the Stream's Creator can pause or resume the Stream")]
+            UnauthorizedPause,
+
             #[msg("Only the Stream's Creator can renounce the Stream's cancelability")]
             UnauthorizedRenounce,
 
+            #[msg("Only the Stream's Creator can top up the Stream")]
+            UnauthorizedTopUp,
+
+            #[msg("Only the Stream's Recipient can transfer the Stream")]
+            UnauthorizedTransfer,
+
             #[msg("Only the Stream's Recipient can withdraw from the Stream")]
             UnauthorizedWithdraw,
         }
 
+        #[error_code]
+        pub enum Campaign {
+            #[msg("Only the Campaign's Creator can claw back the Campaign's funds")]
+            UnauthorizedClawback,
+        }
+
+        #[error_code]
+        pub enum Flow {
+            #[msg("Only the Flow Stream's Sender can manage the Flow Stream")]
+            UnauthorizedSender,
+
+            #[msg("Only the Flow Stream's Sender or Recipient can void the Flow Stream")]
+            UnauthorizedVoid,
+        }
+
+        #[error_code]
+        pub enum Config {
+            #[msg("Config creator is unauthorized")]
+            UnauthorizedCreator,
+
+            #[msg("Only the Config's Authority can update the Config or collect fees")]
+            UnauthorizedAuthority,
+        }
+
         #[error_code]
         pub enum Counter {
             #[msg("Stream Counter creator is unauthorized")]
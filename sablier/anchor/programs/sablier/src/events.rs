@@ -0,0 +1,277 @@
+use crate::{LockupDynamicStream, LockupLinearStream, LockupTranchedStream, Segment, Tranche};
+use anchor_lang::prelude::*;
+
+/// Emitted when a lockup linear stream is created, either directly, in a batch or through a campaign claim.
+#[event]
+pub struct CreateLockupLinearStream {
+    pub stream: Pubkey,
+    pub stream_index: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    pub nft_mint: Pubkey,
+    pub deposited_amount: u64,
+    pub protocol_fee: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub start_unlock: u64,
+    pub cliff_unlock: u64,
+    pub is_cancelable: bool,
+    pub is_transferable: bool,
+}
+
+/// Emitted when a lockup dynamic stream is created.
+#[event]
+pub struct CreateLockupDynamicStream {
+    pub stream: Pubkey,
+    pub stream_index: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    pub deposited_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub segments: Vec<Segment>,
+    pub is_cancelable: bool,
+    pub is_transferable: bool,
+}
+
+/// Emitted when a lockup tranched stream is created.
+#[event]
+pub struct CreateLockupTranchedStream {
+    pub stream: Pubkey,
+    pub stream_index: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    pub deposited_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub tranches: Vec<Tranche>,
+    pub is_cancelable: bool,
+    pub is_transferable: bool,
+}
+
+/// Emitted when tokens are withdrawn from a lockup stream of any kind.
+#[event]
+pub struct WithdrawFromLockupStream {
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    /// Token account that received the withdrawn tokens.
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a lockup stream of any kind is canceled.
+#[event]
+pub struct CancelLockupStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    /// Amount refunded to the sender.
+    pub refunded_amount: u64,
+    /// Streamed amount left in the treasury for the recipient to withdraw.
+    pub recipient_amount: u64,
+}
+
+/// Emitted when a depleted lockup stream is closed and its rent returned to the sender.
+#[event]
+pub struct CloseLockupStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+}
+
+/// Emitted when the recipient of a lockup linear stream approves an operator to withdraw on their behalf.
+#[event]
+pub struct ApproveLockupStreamOperator {
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub operator: Pubkey,
+}
+
+/// Emitted when the approved operator of a lockup linear stream is revoked.
+#[event]
+pub struct RevokeLockupStreamOperator {
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub operator: Pubkey,
+}
+
+/// Emitted when a lockup linear stream is paused.
+#[event]
+pub struct PauseLockupStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+}
+
+/// Emitted when a paused lockup linear stream is resumed.
+#[event]
+pub struct ResumeLockupStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    /// How long the stream was paused, by which its schedule was pushed back.
+    pub pause_duration: i64,
+    pub end_time: i64,
+}
+
+/// Emitted when the cancelability of a lockup stream of any kind is renounced.
+#[event]
+pub struct RenounceLockupStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+}
+
+/// Emitted when tokens are added to an active lockup linear stream.
+#[event]
+pub struct TopUpLockupStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub deposited_amount: u64,
+    pub protocol_fee: u64,
+    /// Start time of the stream after the top-up, which moves forward when its rate increases.
+    pub start_time: i64,
+    /// End time of the stream after the top-up, which moves back when it is extended.
+    pub end_time: i64,
+}
+
+/// Emitted when a lockup stream is transferred to a new recipient.
+#[event]
+pub struct TransferLockupStream {
+    pub stream: Pubkey,
+    pub old_recipient: Pubkey,
+    pub new_recipient: Pubkey,
+}
+
+/// Emitted when a flow stream is created.
+#[event]
+pub struct CreateFlowStream {
+    pub stream: Pubkey,
+    pub stream_index: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub token_mint: Pubkey,
+    pub rate_per_second: u64,
+}
+
+/// Emitted when tokens are deposited into a flow stream.
+#[event]
+pub struct DepositIntoFlowStream {
+    pub stream: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the rate per second of a flow stream changes.
+#[event]
+pub struct AdjustFlowStreamRate {
+    pub stream: Pubkey,
+    pub old_rate_per_second: u64,
+    pub new_rate_per_second: u64,
+}
+
+/// Emitted when a flow stream is paused.
+#[event]
+pub struct PauseFlowStream {
+    pub stream: Pubkey,
+    /// Debt owed to the recipient at the time of the pause.
+    pub total_debt: u64,
+}
+
+/// Emitted when a paused flow stream is restarted.
+#[event]
+pub struct RestartFlowStream {
+    pub stream: Pubkey,
+    pub rate_per_second: u64,
+}
+
+/// Emitted when tokens are withdrawn from a flow stream to its recipient.
+#[event]
+pub struct WithdrawFromFlowStream {
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when tokens are refunded from a flow stream to its sender.
+#[event]
+pub struct RefundFromFlowStream {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a flow stream is voided by its sender or recipient.
+#[event]
+pub struct VoidFlowStream {
+    pub stream: Pubkey,
+    pub signer: Pubkey,
+    /// Uncovered debt that the recipient will never receive.
+    pub forgiven_debt: u64,
+}
+
+impl CreateLockupLinearStream {
+    pub fn new(address: Pubkey, stream: &LockupLinearStream) -> Self {
+        let base_stream = &stream.base_stream;
+
+        Self {
+            stream: address,
+            stream_index: base_stream.index,
+            sender: base_stream.sender,
+            recipient: base_stream.recipient,
+            token_mint: base_stream.token_mint,
+            nft_mint: stream.nft_mint,
+            deposited_amount: base_stream.amounts.deposited,
+            protocol_fee: base_stream.amounts.protocol_fee,
+            start_time: base_stream.start_time,
+            cliff_time: stream.cliff_time,
+            end_time: base_stream.end_time,
+            start_unlock: stream.start_unlock,
+            cliff_unlock: stream.cliff_unlock,
+            is_cancelable: base_stream.is_cancelable,
+            is_transferable: base_stream.is_transferable,
+        }
+    }
+}
+
+impl CreateLockupDynamicStream {
+    pub fn new(address: Pubkey, stream: &LockupDynamicStream) -> Self {
+        let base_stream = &stream.base_stream;
+
+        Self {
+            stream: address,
+            stream_index: base_stream.index,
+            sender: base_stream.sender,
+            recipient: base_stream.recipient,
+            token_mint: base_stream.token_mint,
+            deposited_amount: base_stream.amounts.deposited,
+            start_time: base_stream.start_time,
+            end_time: base_stream.end_time,
+            segments: stream.segments.clone(),
+            is_cancelable: base_stream.is_cancelable,
+            is_transferable: base_stream.is_transferable,
+        }
+    }
+}
+
+impl CreateLockupTranchedStream {
+    pub fn new(address: Pubkey, stream: &LockupTranchedStream) -> Self {
+        let base_stream = &stream.base_stream;
+
+        Self {
+            stream: address,
+            stream_index: base_stream.index,
+            sender: base_stream.sender,
+            recipient: base_stream.recipient,
+            token_mint: base_stream.token_mint,
+            deposited_amount: base_stream.amounts.deposited,
+            start_time: base_stream.start_time,
+            end_time: base_stream.end_time,
+            tranches: stream.tranches.clone(),
+            is_cancelable: base_stream.is_cancelable,
+            is_transferable: base_stream.is_transferable,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{error::Error, StreamHook};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        hash::hash,
+        instruction::{AccountMeta, Instruction},
+        program::invoke,
+    },
+};
+
+/// Name of the instruction called on a recipient's hook program after a withdrawal.
+pub const WITHDRAW_HOOK: &str = "on_lockup_stream_withdraw";
+/// Name of the instruction called on a sender's hook program after a cancelation.
+pub const CANCEL_HOOK: &str = "on_lockup_stream_cancel";
+
+/// Arguments passed to the `on_lockup_stream_withdraw` hook.
+#[derive(AnchorSerialize)]
+pub struct WithdrawHookArgs {
+    pub stream: Pubkey,
+    pub stream_index: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Arguments passed to the `on_lockup_stream_cancel` hook.
+#[derive(AnchorSerialize)]
+pub struct CancelHookArgs {
+    pub stream: Pubkey,
+    pub stream_index: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub refunded_amount: u64,
+    pub recipient_amount: u64,
+}
+
+/// Calls the hook program registered for an account, if there is one.
+///
+/// The hook instruction is encoded like an Anchor instruction, i.e. the first 8 bytes of
+/// `sha256("global:<hook_name>")` followed by the borsh-encoded arguments, so that hook programs
+/// can be written with Anchor. It receives the stream as its first, read-only account, followed by
+/// the remaining accounts that come after the hook program itself, which must be the first one.
+///
+/// Signatures are never forwarded: every remaining account reaches the hook as a non-signer, so
+/// that a hook registered by one party cannot act on behalf of whoever signed the instruction.
+///
+/// The stream is persisted before the call, so that the hook reads its updated amounts and flags
+/// rather than the ones it had when the instruction started.
+pub fn call_stream_hook<'info, T>(
+    stream_hook: &AccountInfo<'info>,
+    stream: &Account<'info, T>,
+    remaining_accounts: &[AccountInfo<'info>],
+    hook_name: &str,
+    args: &impl AnchorSerialize,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    // Accounts without a registered hook are left alone
+    if stream_hook.owner != &crate::ID || stream_hook.data_is_empty() {
+        return Ok(());
+    }
+    let stream_hook = StreamHook::try_deserialize(&mut &stream_hook.try_borrow_data()?[..])?;
+
+    let (hook_program, hook_accounts) = remaining_accounts
+        .split_first()
+        .filter(|(program, _)| program.key() == stream_hook.program_id && program.executable)
+        .ok_or(Error::Validation::Stream::InvalidHookProgram)?;
+
+    stream.exit(&crate::ID)?;
+
+    let mut data = hash(format!("global:{}", hook_name).as_bytes()).to_bytes()[..8].to_vec();
+    args.serialize(&mut data)?;
+
+    let accounts = std::iter::once(AccountMeta::new_readonly(stream.key(), false))
+        .chain(hook_accounts.iter().map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: false,
+            is_writable: account.is_writable,
+        }))
+        .collect();
+
+    let mut account_infos = vec![stream.to_account_info()];
+    account_infos.extend_from_slice(hook_accounts);
+    account_infos.push(hook_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: hook_program.key(),
+            accounts,
+            data,
+        },
+        &account_infos,
+    )?;
+    msg!("Called the {} hook of {} 🪝", hook_name, hook_program.key());
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use crate::{
+    calculate_transfer_fee, error::Error, events, validate_create, validate_team_stream_counter,
+    validate_token_mint, BaseStream, StreamCounter,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+/// Validates the creation of a lockup stream indexed by a team stream counter.
+///
+/// The schedule of these streams must add up to what the treasury receives, which a transfer fee
+/// would cut.
+pub fn validate_create_team_lockup_stream(
+    stream_counter: &Account<StreamCounter>,
+    token_mint: &AccountInfo,
+    start_time: i64,
+    end_time: i64,
+    amount: u64,
+) -> Result<()> {
+    validate_team_stream_counter(stream_counter)?;
+    validate_create(start_time, end_time, amount)?;
+
+    validate_token_mint(token_mint)?;
+    require!(
+        calculate_transfer_fee(token_mint, amount)? == 0,
+        Error::Validation::Stream::UnsupportedTransferFee
+    );
+
+    Ok(())
+}
+
+/// Transfers the deposit of a new lockup stream from the sender into its treasury.
+pub fn fund_lockup_stream<'info>(
+    sender: &Signer<'info>,
+    sender_token_account: &InterfaceAccount<'info, TokenAccount>,
+    treasury_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from: sender_token_account.to_account_info(),
+        to: treasury_token_account.to_account_info(),
+        mint: token_mint.to_account_info(),
+        authority: sender.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+    transfer_checked(cpi_ctx, amount, token_mint.decimals)?;
+    msg!("Transferred {} tokens to the treasury 💸", amount);
+
+    Ok(())
+}
+
+/// Accounts to transfer tokens out of a lockup stream's treasury, which is its own authority.
+pub struct LockupTreasuryTransfer<'a, 'info> {
+    pub treasury_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub destination_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    /// Seeds of the treasury, including its bump.
+    pub treasury_seeds: &'a [&'a [u8]],
+}
+
+impl LockupTreasuryTransfer<'_, '_> {
+    /// Transfers `amount` tokens from the treasury to the destination.
+    pub fn transfer(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: self.treasury_token_account.to_account_info(),
+            to: self.destination_token_account.to_account_info(),
+            mint: self.token_mint.to_account_info(),
+            authority: self.treasury_token_account.to_account_info(),
+        };
+        let signer_seeds = &[self.treasury_seeds];
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+            .with_signer(signer_seeds);
+        transfer_checked(cpi_ctx, amount, self.token_mint.decimals)?;
+        msg!("Transfer successful 💸");
+
+        Ok(())
+    }
+}
+
+/// Refunds the amount that has not been streamed yet to the sender and marks the stream as canceled.
+pub fn cancel_lockup_stream(
+    stream_key: Pubkey,
+    base_stream: &mut BaseStream,
+    streamed_amount: u64,
+    refund: LockupTreasuryTransfer,
+) -> Result<()> {
+    let refundable_amount = base_stream
+        .amounts
+        .deposited
+        .checked_sub(streamed_amount)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!("Refundable amount calculated: {} 💰", refundable_amount);
+
+    if refundable_amount > 0 {
+        refund.transfer(refundable_amount)?;
+    } else {
+        msg!("No amount to transfer 😟");
+    }
+
+    // Mark stream as canceled
+    base_stream.is_canceled = true;
+    base_stream.is_cancelable = false;
+    base_stream.amounts.refunded = base_stream
+        .amounts
+        .refunded
+        .checked_add(refundable_amount)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!("Stream marked as canceled 🚫");
+
+    let recipient_amount = streamed_amount
+        .checked_sub(base_stream.amounts.withdrawn)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    emit!(events::CancelLockupStream {
+        stream: stream_key,
+        sender: base_stream.sender,
+        recipient: base_stream.recipient,
+        token_mint: base_stream.token_mint,
+        refunded_amount: refundable_amount,
+        recipient_amount,
+    });
+
+    Ok(())
+}
+
+/// Transfers `amount` to the recipient, provided that it was streamed and not withdrawn yet.
+///
+/// `recipient` is the one reported in the withdrawal event, which is the stored recipient unless
+/// the stream type tracks it elsewhere.
+pub fn withdraw_from_lockup_stream(
+    stream_key: Pubkey,
+    base_stream: &mut BaseStream,
+    recipient: Pubkey,
+    streamed_amount: u64,
+    amount: u64,
+    withdrawal: LockupTreasuryTransfer,
+) -> Result<()> {
+    let available_amount = streamed_amount
+        .checked_sub(base_stream.amounts.withdrawn)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Streamed Amount: {} | Available Amount: {} 🏧",
+        streamed_amount,
+        available_amount
+    );
+    require!(
+        available_amount >= amount,
+        Error::Validation::Stream::ExceedsBalance
+    );
+    msg!("Validation successful ✅ Withdrawing from stream... ⏳");
+
+    withdrawal.transfer(amount)?;
+
+    base_stream.amounts.withdrawn = base_stream
+        .amounts
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    emit!(events::WithdrawFromLockupStream {
+        stream: stream_key,
+        recipient,
+        token_mint: base_stream.token_mint,
+        destination: withdrawal.destination_token_account.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,14 @@
+pub use hook::*;
+pub mod hook;
+
 pub use initialize::*;
 pub mod initialize;
 
+pub use lockup::*;
+pub mod lockup;
+
+pub use token::*;
+pub mod token;
+
 pub use validate::*;
 pub mod validate;
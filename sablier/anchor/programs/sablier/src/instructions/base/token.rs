@@ -0,0 +1,77 @@
+use crate::error::Error;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::{Account, Mint},
+};
+
+/// Mint extensions that streams cannot hold safely.
+///
+/// Non-transferable tokens cannot leave the treasury, confidential balances cannot be accounted
+/// for, a permanent delegate can drain the treasury at any time, and transfer hooks would need
+/// accounts that the program does not forward.
+const UNSUPPORTED_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::NonTransferable,
+    ExtensionType::ConfidentialTransferMint,
+    ExtensionType::PermanentDelegate,
+    ExtensionType::TransferHook,
+];
+
+/// Validates that the token mint has none of the extensions that streams do not support.
+///
+/// Mints owned by the legacy token program have no extensions and are always supported.
+pub fn validate_token_mint(token_mint: &AccountInfo) -> Result<()> {
+    let data = token_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+
+    let has_unsupported_extension = mint
+        .get_extension_types()?
+        .iter()
+        .any(|extension| UNSUPPORTED_MINT_EXTENSIONS.contains(extension));
+    require!(
+        !has_unsupported_extension,
+        Error::Validation::Stream::UnsupportedMintExtension
+    );
+
+    Ok(())
+}
+
+/// Computes the transfer fee withheld when transferring `amount` tokens of the given mint.
+///
+/// The fee is withheld from the destination account, so a treasury always sends exactly the
+/// amount that it is debited, while it only receives the amount net of the fee.
+pub fn calculate_transfer_fee(token_mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = token_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+
+    let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the amount received by the destination when transferring `amount` tokens of the given mint.
+pub fn calculate_net_transfer_amount(token_mint: &AccountInfo, amount: u64) -> Result<u64> {
+    amount
+        .checked_sub(calculate_transfer_fee(token_mint, amount)?)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Returns the transfer fees withheld in the given token account, which keep it from being closed
+/// until they are harvested to the mint.
+pub fn get_withheld_transfer_fee(token_account: &AccountInfo) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<Account>::unpack(&data)?;
+
+    Ok(account
+        .get_extension::<TransferFeeAmount>()
+        .map_or(0, |transfer_fee_amount| {
+            transfer_fee_amount.withheld_amount.into()
+        }))
+}
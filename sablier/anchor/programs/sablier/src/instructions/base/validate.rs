@@ -1,42 +1,145 @@
-use crate::{error::Error, BaseStream, StreamCounter, TEAM_PUB_KEY};
+use crate::{error::Error, BaseStream, StreamCounter, StreamStatus, TEAM_PUB_KEY};
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
+/// Actions that move a stream through its lifecycle, each allowed from a subset of its statuses.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamAction {
+    Cancel,
+    Close,
+    Pause,
+    Renounce,
+    Resume,
+    TopUp,
+    Transfer,
+    Withdraw,
+}
+
+/// Validates that the given action is legal for a stream in the given status.
+///
+/// This is the single source of truth for the stream lifecycle:
+///
+/// | Action   | Pending | Streaming | Paused | Settled | Canceled | Depleted |
+/// |----------|---------|-----------|--------|---------|----------|----------|
+/// | Cancel   | ✅      | ✅        | ❌     | ❌      | ❌       | ❌       |
+/// | Pause    | ✅      | ✅        | ❌     | ❌      | ❌       | ❌       |
+/// | Renounce | ✅      | ✅        | ❌     | ❌      | ❌       | ❌       |
+/// | TopUp    | ✅      | ✅        | ❌     | ❌      | ❌       | ❌       |
+/// | Resume   | ❌      | ❌        | ✅     | ❌      | ❌       | ❌       |
+/// | Withdraw | ❌      | ✅        | ✅     | ✅      | ✅       | ❌       |
+/// | Transfer | ✅      | ✅        | ✅     | ✅      | ✅       | ✅       |
+/// | Close    | ❌      | ❌        | ❌     | ❌      | ❌       | ✅       |
+pub fn validate_transition(status: StreamStatus, action: StreamAction) -> Result<()> {
+    use StreamStatus::*;
+
+    match (action, status) {
+        (StreamAction::Transfer, _)
+        | (StreamAction::Close, Depleted)
+        | (StreamAction::Resume, Paused)
+        | (StreamAction::Withdraw, Streaming | Paused | Settled | Canceled)
+        | (
+            StreamAction::Cancel
+            | StreamAction::Pause
+            | StreamAction::Renounce
+            | StreamAction::TopUp,
+            Pending | Streaming,
+        ) => Ok(()),
+
+        (StreamAction::Close, _) => err!(Error::Validation::Stream::NotDepleted),
+        (StreamAction::Resume, _) => err!(Error::Validation::Stream::NotPaused),
+        (_, Depleted) => err!(Error::Validation::Stream::EmptyStream),
+        (StreamAction::Withdraw, _) => err!(Error::Validation::Stream::StreamNotStarted),
+        (_, Canceled) => err!(Error::Validation::Stream::AlreadyCanceled),
+        (_, Paused) => err!(Error::Validation::Stream::AlreadyPaused),
+        (StreamAction::Renounce, _) => {
+            err!(Error::Validation::Stream::NotRenounceablePastEndTime)
+        }
+        (StreamAction::TopUp, _) => err!(Error::Validation::Stream::NotToppablePastEndTime),
+        _ => err!(Error::Validation::Stream::NotCancelablePastEndTime),
+    }
+}
+
 /// Validates if a stream can be canceled by the given sender.
-pub fn validate_cancel(sender: Pubkey, base_stream: &BaseStream) -> Result<()> {
+pub fn validate_cancel(
+    sender: Pubkey,
+    base_stream: &BaseStream,
+    status: StreamStatus,
+) -> Result<()> {
     require!(
         sender == base_stream.sender,
         Error::Authorization::Stream::UnauthorizedCancel
     );
-    require!(
-        !base_stream.is_canceled,
-        Error::Validation::Stream::AlreadyCanceled
-    );
+    validate_transition(status, StreamAction::Cancel)?;
     require!(
         base_stream.is_cancelable,
         Error::Validation::Stream::NotCancelable
     );
+
+    Ok(())
+}
+
+/// Validates if a stream can be paused by the given sender.
+///
+/// Pausing is gated like canceling, since both let the sender halt what the recipient is owed.
+pub fn validate_pause(
+    sender: Pubkey,
+    base_stream: &BaseStream,
+    status: StreamStatus,
+) -> Result<()> {
     require!(
-        base_stream.end_time > Clock::get()?.unix_timestamp,
-        Error::Validation::Stream::NotCancelablePastEndTime
+        sender == base_stream.sender,
+        Error::Authorization::Stream::UnauthorizedPause
+    );
+    validate_transition(status, StreamAction::Pause)?;
+    require!(
+        base_stream.is_cancelable,
+        Error::Validation::Stream::NotCancelable
     );
 
     Ok(())
 }
 
-/// Validates conditions for creating a new stream.
-pub fn validate_create(
-    stream_counter: &Account<StreamCounter>,
-    start_time: i64,
-    end_time: i64,
-    amount: u64,
+/// Validates if a paused stream can be resumed by the given sender.
+pub fn validate_resume(
+    sender: Pubkey,
+    base_stream: &BaseStream,
+    status: StreamStatus,
 ) -> Result<()> {
+    require!(
+        sender == base_stream.sender,
+        Error::Authorization::Stream::UnauthorizedPause
+    );
+    validate_transition(status, StreamAction::Resume)
+}
+
+/// Validates if a stream can be closed by the given sender.
+///
+/// Only depleted streams can be closed, i.e. those whose deposit was entirely withdrawn or refunded.
+pub fn validate_close(
+    sender: Pubkey,
+    base_stream: &BaseStream,
+    status: StreamStatus,
+) -> Result<()> {
+    require!(
+        sender == base_stream.sender,
+        Error::Authorization::Stream::UnauthorizedClose
+    );
+    validate_transition(status, StreamAction::Close)
+}
+
+/// Validates that a global stream counter was initialized by the Sablier Team.
+pub fn validate_team_stream_counter(stream_counter: &Account<StreamCounter>) -> Result<()> {
     let team_authority = Pubkey::from_str(TEAM_PUB_KEY).unwrap();
     require!(
         stream_counter.authority == team_authority,
         Error::Authorization::Counter::UnauthorizedCounter
     );
 
+    Ok(())
+}
+
+/// Validates conditions for creating a new stream.
+pub fn validate_create(start_time: i64, end_time: i64, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
     require!(
         start_time >= clock.unix_timestamp,
@@ -54,43 +157,153 @@ pub fn validate_create(
 }
 
 /// Validates if a stream's cancelability can be renounced by the given sender.
-pub fn validate_renounce(sender: Pubkey, base_stream: &BaseStream) -> Result<()> {
+pub fn validate_renounce(
+    sender: Pubkey,
+    base_stream: &BaseStream,
+    status: StreamStatus,
+) -> Result<()> {
     require!(
         sender == base_stream.sender,
         Error::Authorization::Stream::UnauthorizedRenounce
     );
-    require!(
-        !base_stream.is_canceled,
-        Error::Validation::Stream::AlreadyCanceled
-    );
+    validate_transition(status, StreamAction::Renounce)?;
     require!(
         base_stream.is_cancelable,
         Error::Validation::Stream::NotCancelable
     );
-    require!(
-        base_stream.end_time > Clock::get()?.unix_timestamp,
-        Error::Validation::Stream::NotRenounceablePastEndTime
-    );
 
     Ok(())
 }
 
-/// Validates if a stream can be withdrawn from by the given recipient.
-pub fn validate_withdraw(recipient: Pubkey, amount: u64, base_stream: &BaseStream) -> Result<()> {
+/// Validates if a stream can be topped up by the given sender.
+pub fn validate_top_up(
+    sender: Pubkey,
+    base_stream: &BaseStream,
+    status: StreamStatus,
+    amount: u64,
+) -> Result<()> {
     require!(
-        recipient == base_stream.recipient,
-        Error::Authorization::Stream::UnauthorizedWithdraw
+        sender == base_stream.sender,
+        Error::Authorization::Stream::UnauthorizedTopUp
     );
     require!(amount > 0, Error::Validation::Stream::InvalidAmount);
+    validate_transition(status, StreamAction::TopUp)
+}
+
+/// Validates if a stream can be transferred.
+///
+/// Authorization is enforced by the caller through the holding of the stream NFT.
+pub fn validate_transfer(base_stream: &BaseStream, status: StreamStatus) -> Result<()> {
+    validate_transition(status, StreamAction::Transfer)?;
     require!(
-        base_stream.start_time < Clock::get()?.unix_timestamp,
-        Error::Validation::Stream::StreamNotStarted,
-    );
-    require!(
-        base_stream.amounts.deposited
-            > base_stream.amounts.withdrawn + base_stream.amounts.refunded,
-        Error::Validation::Stream::EmptyStream
+        base_stream.is_transferable,
+        Error::Validation::Stream::NotTransferable
     );
 
     Ok(())
 }
+
+/// Validates if the given amount can be withdrawn from a stream.
+///
+/// Authorization is enforced by the caller, either through the stream's recipient or its NFT holder.
+pub fn validate_withdraw(amount: u64, status: StreamStatus) -> Result<()> {
+    require!(amount > 0, Error::Validation::Stream::InvalidAmount);
+    validate_transition(status, StreamAction::Withdraw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [StreamStatus; 6] = [
+        StreamStatus::Pending,
+        StreamStatus::Streaming,
+        StreamStatus::Paused,
+        StreamStatus::Settled,
+        StreamStatus::Canceled,
+        StreamStatus::Depleted,
+    ];
+
+    /// Asserts that the action is allowed from exactly the given statuses.
+    fn assert_allowed_from(action: StreamAction, allowed: &[StreamStatus]) {
+        for status in STATUSES {
+            assert_eq!(
+                validate_transition(status, action).is_ok(),
+                allowed.contains(&status),
+                "{:?} from {:?}",
+                action,
+                status
+            );
+        }
+    }
+
+    #[test]
+    fn cancels_pauses_renounces_and_tops_up_only_before_the_end() {
+        for action in [
+            StreamAction::Cancel,
+            StreamAction::Pause,
+            StreamAction::Renounce,
+            StreamAction::TopUp,
+        ] {
+            assert_allowed_from(action, &[StreamStatus::Pending, StreamStatus::Streaming]);
+        }
+    }
+
+    #[test]
+    fn resumes_only_paused_streams() {
+        assert_allowed_from(StreamAction::Resume, &[StreamStatus::Paused]);
+    }
+
+    #[test]
+    fn withdraws_once_started_until_depleted() {
+        assert_allowed_from(
+            StreamAction::Withdraw,
+            &[
+                StreamStatus::Streaming,
+                StreamStatus::Paused,
+                StreamStatus::Settled,
+                StreamStatus::Canceled,
+            ],
+        );
+    }
+
+    #[test]
+    fn transfers_in_every_status() {
+        assert_allowed_from(StreamAction::Transfer, &STATUSES);
+    }
+
+    #[test]
+    fn closes_only_depleted_streams() {
+        assert_allowed_from(StreamAction::Close, &[StreamStatus::Depleted]);
+    }
+
+    #[test]
+    fn rejects_with_the_error_of_the_status() {
+        let error_of = |status, action| validate_transition(status, action).unwrap_err();
+
+        assert_eq!(
+            error_of(StreamStatus::Depleted, StreamAction::Renounce),
+            error!(Error::Validation::Stream::EmptyStream)
+        );
+        assert_eq!(
+            error_of(StreamStatus::Canceled, StreamAction::Cancel),
+            error!(Error::Validation::Stream::AlreadyCanceled)
+        );
+        assert_eq!(
+            error_of(StreamStatus::Pending, StreamAction::Withdraw),
+            error!(Error::Validation::Stream::StreamNotStarted)
+        );
+        assert_eq!(
+            error_of(StreamStatus::Settled, StreamAction::Cancel),
+            error!(Error::Validation::Stream::NotCancelablePastEndTime)
+        );
+        assert_eq!(
+            error_of(StreamStatus::Settled, StreamAction::TopUp),
+            error!(Error::Validation::Stream::NotToppablePastEndTime)
+        );
+        assert_eq!(
+            error_of(StreamStatus::Paused, StreamAction::Renounce),
+            error!(Error::Validation::Stream::AlreadyPaused)
+        );
+    }
+}
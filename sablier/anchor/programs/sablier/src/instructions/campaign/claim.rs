@@ -0,0 +1,272 @@
+use crate::{
+    build_lockup_linear_stream,
+    error::Error,
+    fund_lockup_linear_stream,
+    seeds::{
+        CAMPAIGN, CAMPAIGN_CLAIM_RECEIPT, CAMPAIGN_TREASURY, CONFIG, LOCKUP_LINEAR_NFT_MINT,
+        LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_STREAM_COUNTER, LOCKUP_LINEAR_TREASURY,
+        PROTOCOL_FEE_VAULT,
+    },
+    Campaign, ClaimReceipt, Config, FundLockupLinearStream, LockupLinearStream,
+    LockupLinearStreamAddresses, LockupLinearStreamParams, MintLockupLinearStreamNft,
+    StreamCounter, ANCHOR_DISCRIMINATOR,
+};
+use anchor_lang::{prelude::*, solana_program::keccak};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Claims an allocation from a campaign, creating a lockup linear stream for the claimant.
+///
+/// The stream starts at the time of the claim and follows the campaign's stream template. The
+/// protocol fee is taken out of the claimed amount, like on any other stream creation.
+pub fn process_claim_from_campaign(
+    ctx: Context<ClaimFromCampaign>,
+    index: u64,
+    amount: u64,
+    merkle_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    msg!("Validating Claim Operation... 🛂");
+    let campaign = &ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        now < campaign.expiry_time,
+        Error::Validation::Campaign::Expired
+    );
+
+    let leaf = keccak::hashv(&[
+        &index.to_le_bytes(),
+        ctx.accounts.claimant.key().as_ref(),
+        &amount.to_le_bytes(),
+    ]);
+    require!(
+        verify_merkle_proof(&merkle_proof, campaign.merkle_root, leaf.to_bytes()),
+        Error::Validation::Campaign::InvalidMerkleProof
+    );
+
+    // Claimed streams are counted against the campaign's creator, who is their sender
+    let stream_counter = &mut ctx.accounts.stream_counter;
+    stream_counter.authority = campaign.creator;
+
+    let template = &campaign.stream_template;
+    let start_time = now;
+    let cliff_time = start_time
+        .checked_add(template.cliff_duration)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    let end_time = start_time
+        .checked_add(template.total_duration)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    // The stream is cancelable by the campaign's creator
+    let (stream, deposited_amount) = build_lockup_linear_stream(
+        LockupLinearStreamParams {
+            name: campaign.name.clone(),
+            sender: campaign.creator,
+            recipient: ctx.accounts.claimant.key(),
+            amount,
+            broker_fee: 0,
+            start_time,
+            cliff_time,
+            end_time,
+            is_cancelable: template.is_cancelable,
+            is_transferable: template.is_transferable,
+            start_unlock: 0,
+            cliff_unlock: 0,
+            rent_payer: ctx.accounts.claimant.key(),
+        },
+        LockupLinearStreamAddresses {
+            index: stream_counter.stream_index,
+            bump: ctx.bumps.stream,
+            treasury_bump: ctx.bumps.treasury_token_account,
+            nft_mint: ctx.accounts.nft_mint.key(),
+        },
+        &ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.config.protocol_fee_bps,
+    )?;
+    **ctx.accounts.stream = stream;
+
+    **ctx.accounts.claim_receipt = ClaimReceipt {
+        claimant: ctx.accounts.claimant.key(),
+        stream: ctx.accounts.stream.key(),
+    };
+
+    // The campaign's treasury funds the stream and pays the protocol fee
+    let campaign_key = campaign.key();
+    let campaign_treasury_seeds: &[&[&[u8]]] = &[&[
+        CAMPAIGN_TREASURY.as_ref(),
+        campaign_key.as_ref(),
+        &[ctx.bumps.campaign_treasury_token_account],
+    ]];
+    fund_lockup_linear_stream(
+        FundLockupLinearStream {
+            funder: ctx
+                .accounts
+                .campaign_treasury_token_account
+                .to_account_info(),
+            funder_token_account: ctx
+                .accounts
+                .campaign_treasury_token_account
+                .to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            treasury_token_account: ctx.accounts.treasury_token_account.to_account_info(),
+            fee_vault_token_account: ctx.accounts.fee_vault_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            nft: MintLockupLinearStreamNft {
+                payer: ctx.accounts.claimant.to_account_info(),
+                nft_mint: ctx.accounts.nft_mint.to_account_info(),
+                recipient_nft_token_account: ctx
+                    .accounts
+                    .recipient_nft_token_account
+                    .to_account_info(),
+                nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        },
+        ctx.accounts.stream.key(),
+        &ctx.accounts.stream,
+        deposited_amount,
+        ctx.accounts.token_mint.decimals,
+        ctx.bumps.nft_mint,
+        campaign_treasury_seeds,
+    )?;
+
+    // Increment stream counter
+    stream_counter.stream_index = stream_counter
+        .stream_index
+        .checked_add(1)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream index incremented to {} 🧮",
+        stream_counter.stream_index
+    );
+
+    Ok(())
+}
+
+/// Verifies a Merkle proof by hashing each pair of nodes in sorted order up to the root.
+fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_root = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed_root == root
+}
+
+/// Accounts for `claim_from_campaign`
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ClaimFromCampaign<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [CAMPAIGN.as_ref(), campaign.creator.as_ref(), campaign.name.as_bytes()],
+        has_one = token_mint,
+        bump
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_TREASURY.as_ref(), campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Prevents the same allocation from being claimed twice
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + ClaimReceipt::INIT_SPACE,
+        payer = claimant,
+        seeds = [CAMPAIGN_CLAIM_RECEIPT.as_ref(), campaign.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub claim_receipt: Box<Account<'info, ClaimReceipt>>,
+
+    #[account(
+        init_if_needed,
+        space = ANCHOR_DISCRIMINATOR + StreamCounter::INIT_SPACE,
+        payer = claimant,
+        seeds = [LOCKUP_LINEAR_STREAM_COUNTER.as_ref(), campaign.creator.as_ref()],
+        bump,
+    )]
+    pub stream_counter: Box<Account<'info, StreamCounter>>,
+
+    #[account(
+        seeds = [CONFIG.as_ref()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        token::mint = token_mint,
+        token::authority = fee_vault_token_account,
+        token::token_program = token_program,
+        seeds = [PROTOCOL_FEE_VAULT.as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE,
+        payer = claimant,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            campaign.creator.as_ref(),
+            &stream_counter.stream_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub stream: Box<Account<'info, LockupLinearStream>>,
+
+    #[account(
+        init,
+        payer = claimant,
+        token::mint = token_mint,
+        token::authority = treasury_token_account,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = claimant,
+        mint::decimals = 0,
+        mint::authority = nft_mint,
+        mint::freeze_authority = nft_mint,
+        mint::token_program = nft_token_program,
+        extensions::metadata_pointer::authority = nft_mint,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+        seeds = [LOCKUP_LINEAR_NFT_MINT.as_ref(), stream.key().as_ref()],
+        bump
+    )]
+    pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = claimant,
+        associated_token::mint = nft_mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = nft_token_program,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Program of the stream NFT, which holds its metadata as a Token-2022 extension
+    pub nft_token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
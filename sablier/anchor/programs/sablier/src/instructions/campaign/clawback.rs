@@ -0,0 +1,83 @@
+use crate::{
+    error::Error,
+    seeds::{CAMPAIGN, CAMPAIGN_TREASURY},
+    Campaign,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+/// Transfers the unclaimed funds of an expired campaign back to its creator.
+pub fn process_clawback_from_campaign(ctx: Context<ClawbackFromCampaign>) -> Result<()> {
+    msg!("Validating Clawback Operation... 🛂");
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.campaign.expiry_time,
+        Error::Validation::Campaign::NotExpired
+    );
+    msg!("Validation successful ✅ Clawing back funds... ⏳");
+
+    let unclaimed_amount = ctx.accounts.campaign_treasury_token_account.amount;
+    if unclaimed_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .campaign_treasury_token_account
+                .to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            authority: ctx
+                .accounts
+                .campaign_treasury_token_account
+                .to_account_info(),
+        };
+
+        let campaign_key = ctx.accounts.campaign.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            CAMPAIGN_TREASURY.as_ref(),
+            campaign_key.as_ref(),
+            &[ctx.bumps.campaign_treasury_token_account],
+        ]];
+
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+            .with_signer(signer_seeds);
+        transfer_checked(cpi_ctx, unclaimed_amount, ctx.accounts.token_mint.decimals)?;
+        msg!("Clawed back {} tokens 💸", unclaimed_amount);
+    } else {
+        msg!("No amount to transfer 😟");
+    }
+
+    Ok(())
+}
+
+/// Accounts for `clawback_from_campaign`
+#[derive(Accounts)]
+pub struct ClawbackFromCampaign<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [CAMPAIGN.as_ref(), campaign.creator.as_ref(), campaign.name.as_bytes()],
+        has_one = creator @ Error::Authorization::Campaign::UnauthorizedClawback,
+        has_one = token_mint,
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_TREASURY.as_ref(), campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
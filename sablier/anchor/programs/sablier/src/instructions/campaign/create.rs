@@ -0,0 +1,109 @@
+use crate::{
+    error::Error,
+    seeds::{CAMPAIGN, CAMPAIGN_TREASURY},
+    validate_token_mint, Campaign, StreamTemplate, ANCHOR_DISCRIMINATOR, MAX_CAMPAIGN_NAME_LENGTH,
+    MAX_STREAM_TEMPLATE_DURATION,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+/// Creates a new airdrop campaign, transferring the funds to be claimed to the campaign's treasury.
+pub fn process_create_campaign(
+    ctx: Context<CreateCampaign>,
+    name: String,
+    merkle_root: [u8; 32],
+    amount: u64,
+    expiry_time: i64,
+    stream_template: StreamTemplate,
+) -> Result<()> {
+    msg!("Validating Create Campaign Operation... 🛂");
+    require!(
+        name.len() <= MAX_CAMPAIGN_NAME_LENGTH,
+        Error::Validation::Campaign::InvalidName
+    );
+    require!(amount > 0, Error::Validation::Stream::InvalidAmount);
+    require!(
+        expiry_time > Clock::get()?.unix_timestamp,
+        Error::Validation::Campaign::InvalidExpiryTime
+    );
+    require!(
+        stream_template.total_duration > 0
+            && stream_template.cliff_duration >= 0
+            && stream_template.cliff_duration <= stream_template.total_duration
+            && stream_template.total_duration <= MAX_STREAM_TEMPLATE_DURATION,
+        Error::Validation::Campaign::InvalidStreamTemplate
+    );
+    validate_token_mint(&ctx.accounts.token_mint.to_account_info())?;
+    msg!("Validation successful ✅ Creating campaign... ⏳");
+
+    *ctx.accounts.campaign = Campaign {
+        name: name.clone(),
+        creator: ctx.accounts.creator.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        merkle_root,
+        expiry_time,
+        stream_template,
+    };
+    msg!("Campaign created with name: {} ✨", name);
+
+    // Transfer tokens into the campaign's treasury
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.creator_token_account.to_account_info(),
+        to: ctx
+            .accounts
+            .campaign_treasury_token_account
+            .to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: ctx.accounts.creator.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    transfer_checked(cpi_ctx, amount, ctx.accounts.token_mint.decimals)?;
+    msg!("Transferred {} tokens to the campaign treasury 💸", amount);
+
+    Ok(())
+}
+
+/// Context for creating a campaign
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateCampaign<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + Campaign::INIT_SPACE,
+        payer = creator,
+        seeds = [CAMPAIGN.as_ref(), creator.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = token_mint,
+        token::authority = campaign_treasury_token_account,
+        token::token_program = token_program,
+        seeds = [CAMPAIGN_TREASURY.as_ref(), campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,8 @@
+pub use claim::*;
+pub mod claim;
+
+pub use clawback::*;
+pub mod clawback;
+
+pub use create::*;
+pub mod create;
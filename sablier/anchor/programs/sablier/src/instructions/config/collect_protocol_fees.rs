@@ -0,0 +1,82 @@
+use crate::{
+    error::Error,
+    seeds::{CONFIG, PROTOCOL_FEE_VAULT},
+    Config,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+/// Transfers the protocol fees accumulated for a mint to the fee recipient.
+pub fn process_collect_protocol_fees(ctx: Context<CollectProtocolFees>) -> Result<()> {
+    msg!("Validating Collect Protocol Fees Operation... 🛂");
+    let collected_amount = ctx.accounts.fee_vault_token_account.amount;
+    require!(
+        collected_amount > 0,
+        Error::Validation::Config::NothingToCollect
+    );
+    msg!("Validation successful ✅ Collecting fees... ⏳");
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.fee_vault_token_account.to_account_info(),
+        to: ctx.accounts.fee_recipient_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: ctx.accounts.fee_vault_token_account.to_account_info(),
+    };
+
+    let mint_key = ctx.accounts.token_mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PROTOCOL_FEE_VAULT.as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.fee_vault_token_account],
+    ]];
+
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+        .with_signer(signer_seeds);
+    transfer_checked(cpi_ctx, collected_amount, ctx.accounts.token_mint.decimals)?;
+    msg!("Collected {} tokens in protocol fees 💸", collected_amount);
+
+    Ok(())
+}
+
+/// Accounts for `collect_protocol_fees`
+#[derive(Accounts)]
+pub struct CollectProtocolFees<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_ref()],
+        has_one = authority @ Error::Authorization::Config::UnauthorizedAuthority,
+        has_one = fee_recipient,
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Verified against the config's fee recipient
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_FEE_VAULT.as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = token_mint,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use crate::{
+    error::Error, seeds::CONFIG, validate_protocol_fee, Config, ANCHOR_DISCRIMINATOR, TEAM_PUB_KEY,
+};
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+/// Initializes the global config with the protocol fee and its recipient.
+pub fn process_initialize_config(
+    ctx: Context<InitializeConfig>,
+    fee_recipient: Pubkey,
+    protocol_fee_bps: u16,
+) -> Result<()> {
+    msg!("Validating Initialize Config Operation... 🛂");
+    let allowed_authority = Pubkey::from_str(TEAM_PUB_KEY).unwrap();
+    require!(
+        ctx.accounts.authority.key() == allowed_authority,
+        Error::Authorization::Config::UnauthorizedCreator
+    );
+    validate_protocol_fee(protocol_fee_bps)?;
+    msg!("Validation successful ✅ Initializing config... ⏳");
+
+    *ctx.accounts.config = Config {
+        authority: ctx.accounts.authority.key(),
+        fee_recipient,
+        protocol_fee_bps,
+    };
+    msg!(
+        "Config initialized with a protocol fee of {} bps 🚀",
+        protocol_fee_bps
+    );
+
+    Ok(())
+}
+
+/// Accounts for `initialize_config`
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Config::INIT_SPACE,
+        seeds = [CONFIG.as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
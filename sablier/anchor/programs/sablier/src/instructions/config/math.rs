@@ -0,0 +1,37 @@
+use crate::{error::Error, BPS_SCALE, MAX_BROKER_FEE_BPS, MAX_PROTOCOL_FEE_BPS};
+use anchor_lang::prelude::*;
+
+/// Validates that the protocol fee does not exceed the hard cap.
+pub fn validate_protocol_fee(protocol_fee_bps: u16) -> Result<()> {
+    require!(
+        protocol_fee_bps <= MAX_PROTOCOL_FEE_BPS,
+        Error::Validation::Config::InvalidProtocolFee
+    );
+
+    Ok(())
+}
+
+/// Computes the protocol fee charged on the given amount, rounded down.
+pub fn calculate_protocol_fee(amount: u64, protocol_fee_bps: u16) -> Result<u64> {
+    calculate_fee(amount, protocol_fee_bps)
+}
+
+/// Computes the broker fee charged on the given amount, rounded down.
+pub fn calculate_broker_fee(amount: u64, broker_fee_bps: u16) -> Result<u64> {
+    require!(
+        broker_fee_bps <= MAX_BROKER_FEE_BPS,
+        Error::Validation::Stream::InvalidBrokerFee
+    );
+
+    calculate_fee(amount, broker_fee_bps)
+}
+
+/// Computes the given share of an amount in basis points, rounded down.
+fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .and_then(|fee| fee.checked_div(BPS_SCALE as u128))
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    u64::try_from(fee).map_err(|_| error!(Error::Validation::Stream::ArithmeticOverflow))
+}
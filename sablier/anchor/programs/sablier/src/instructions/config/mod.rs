@@ -0,0 +1,17 @@
+pub use collect_protocol_fees::*;
+pub mod collect_protocol_fees;
+
+pub use initialize::*;
+pub mod initialize;
+
+pub use math::*;
+pub mod math;
+
+pub use register_stream_hook::*;
+pub mod register_stream_hook;
+
+pub use set_protocol_fee::*;
+pub mod set_protocol_fee;
+
+pub use unregister_stream_hook::*;
+pub mod unregister_stream_hook;
@@ -0,0 +1,48 @@
+use crate::{
+    error::Error,
+    seeds::{CONFIG, STREAM_HOOK},
+    Config, StreamHook, ANCHOR_DISCRIMINATOR,
+};
+use anchor_lang::prelude::*;
+
+/// Allowlists a hook program to be called back whenever tokens are withdrawn or refunded to an account.
+pub fn process_register_stream_hook(
+    ctx: Context<RegisterStreamHook>,
+    account: Pubkey,
+    program_id: Pubkey,
+) -> Result<()> {
+    *ctx.accounts.stream_hook = StreamHook {
+        account,
+        program_id,
+        bump: ctx.bumps.stream_hook,
+    };
+    msg!("Hook {} registered for {} 🪝", program_id, account);
+
+    Ok(())
+}
+
+/// Accounts for `register_stream_hook`
+#[derive(Accounts)]
+#[instruction(account: Pubkey)]
+pub struct RegisterStreamHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_ref()],
+        has_one = authority @ Error::Authorization::Config::UnauthorizedAuthority,
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + StreamHook::INIT_SPACE,
+        seeds = [STREAM_HOOK.as_ref(), account.as_ref()],
+        bump
+    )]
+    pub stream_hook: Account<'info, StreamHook>,
+
+    pub system_program: Program<'info, System>,
+}
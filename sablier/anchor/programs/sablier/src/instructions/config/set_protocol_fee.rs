@@ -0,0 +1,34 @@
+use crate::{error::Error, seeds::CONFIG, validate_protocol_fee, Config};
+use anchor_lang::prelude::*;
+
+/// Updates the protocol fee and its recipient, within the hard cap.
+pub fn process_set_protocol_fee(
+    ctx: Context<SetProtocolFee>,
+    fee_recipient: Pubkey,
+    protocol_fee_bps: u16,
+) -> Result<()> {
+    msg!("Validating Set Protocol Fee Operation... 🛂");
+    validate_protocol_fee(protocol_fee_bps)?;
+    msg!("Validation successful ✅ Updating config... ⏳");
+
+    let config = &mut ctx.accounts.config;
+    config.fee_recipient = fee_recipient;
+    config.protocol_fee_bps = protocol_fee_bps;
+    msg!("Protocol fee set to {} bps 🧾", protocol_fee_bps);
+
+    Ok(())
+}
+
+/// Accounts for `set_protocol_fee`
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG.as_ref()],
+        has_one = authority @ Error::Authorization::Config::UnauthorizedAuthority,
+        bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,39 @@
+use crate::{
+    error::Error,
+    seeds::{CONFIG, STREAM_HOOK},
+    Config, StreamHook,
+};
+use anchor_lang::prelude::*;
+
+/// Removes a hook program from the allowlist, returning the rent to the authority.
+pub fn process_unregister_stream_hook(ctx: Context<UnregisterStreamHook>) -> Result<()> {
+    msg!(
+        "Hook {} unregistered for {} 🗑️",
+        ctx.accounts.stream_hook.program_id,
+        ctx.accounts.stream_hook.account
+    );
+
+    Ok(())
+}
+
+/// Accounts for `unregister_stream_hook`
+#[derive(Accounts)]
+pub struct UnregisterStreamHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_ref()],
+        has_one = authority @ Error::Authorization::Config::UnauthorizedAuthority,
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [STREAM_HOOK.as_ref(), stream_hook.account.as_ref()],
+        bump = stream_hook.bump
+    )]
+    pub stream_hook: Account<'info, StreamHook>,
+}
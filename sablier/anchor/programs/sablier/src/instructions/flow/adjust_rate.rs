@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::Error, events, seeds::FLOW_STREAM, snapshot_flow_stream, FlowStream,
+    MAX_FLOW_RATE_PER_SECOND,
+};
+
+/// Changes the rate per second of a flow stream, keeping the debt accrued so far.
+pub fn process_adjust_flow_stream_rate(
+    ctx: Context<AdjustFlowStreamRate>,
+    rate_per_second: u64,
+) -> Result<()> {
+    msg!("Validating Adjust Rate Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    require!(!stream.is_voided, Error::Validation::Flow::AlreadyVoided);
+    require!(
+        stream.rate_per_second > 0,
+        Error::Validation::Flow::AlreadyPaused
+    );
+    require!(
+        rate_per_second > 0 && rate_per_second != stream.rate_per_second,
+        Error::Validation::Flow::InvalidRatePerSecond
+    );
+    require!(
+        rate_per_second <= MAX_FLOW_RATE_PER_SECOND,
+        Error::Validation::Flow::ExceedsMaxRatePerSecond
+    );
+    msg!("Validation successful ✅ Adjusting rate... ⏳");
+
+    snapshot_flow_stream(stream, Clock::get()?.unix_timestamp)?;
+    let old_rate_per_second = stream.rate_per_second;
+    stream.rate_per_second = rate_per_second;
+    msg!("Rate per second adjusted to {} 🎚️", rate_per_second);
+
+    emit!(events::AdjustFlowStreamRate {
+        stream: stream.key(),
+        old_rate_per_second,
+        new_rate_per_second: rate_per_second,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `adjust_flow_stream_rate`
+#[derive(Accounts)]
+pub struct AdjustFlowStreamRate<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump,
+        has_one = sender @ Error::Authorization::Flow::UnauthorizedSender,
+    )]
+    pub stream: Account<'info, FlowStream>,
+}
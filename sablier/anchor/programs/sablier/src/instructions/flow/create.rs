@@ -0,0 +1,118 @@
+use crate::{
+    error::Error,
+    events,
+    seeds::{FLOW_STREAM, FLOW_STREAM_COUNTER, FLOW_TREASURY},
+    validate_token_mint, FlowStream, StreamCounter, ANCHOR_DISCRIMINATOR, MAX_FLOW_RATE_PER_SECOND,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Creates a new flow stream, which starts accruing debt right away at the given rate.
+///
+/// The stream is created without a balance, which the sender or anyone else can then deposit.
+pub fn process_create_flow_stream(
+    ctx: Context<CreateFlowStream>,
+    name: String,
+    recipient: Pubkey,
+    rate_per_second: u64,
+) -> Result<()> {
+    msg!("Validating Create Operation... 🛂");
+    require!(
+        rate_per_second > 0,
+        Error::Validation::Flow::InvalidRatePerSecond
+    );
+    require!(
+        rate_per_second <= MAX_FLOW_RATE_PER_SECOND,
+        Error::Validation::Flow::ExceedsMaxRatePerSecond
+    );
+    validate_token_mint(&ctx.accounts.token_mint.to_account_info())?;
+    msg!("Validation successful ✅ Creating flow stream... ⏳");
+
+    // The counter is created on the sender's first stream
+    let stream_counter = &mut ctx.accounts.stream_counter;
+    stream_counter.authority = ctx.accounts.sender.key();
+    let stream_index = stream_counter.stream_index;
+
+    // Initialize stream account
+    *ctx.accounts.stream = FlowStream {
+        index: stream_index,
+        bump: ctx.bumps.stream,
+        treasury_bump: ctx.bumps.treasury_token_account,
+        name,
+        sender: ctx.accounts.sender.key(),
+        recipient,
+        token_mint: ctx.accounts.token_mint.key(),
+        rate_per_second,
+        snapshot_time: Clock::get()?.unix_timestamp,
+        snapshot_debt: 0,
+        balance: 0,
+        is_voided: false,
+    };
+    msg!("FlowStream created with index: {} ✨", stream_index);
+
+    emit!(events::CreateFlowStream {
+        stream: ctx.accounts.stream.key(),
+        stream_index,
+        sender: ctx.accounts.sender.key(),
+        recipient,
+        token_mint: ctx.accounts.token_mint.key(),
+        rate_per_second,
+    });
+
+    // Increment stream counter
+    stream_counter.stream_index = stream_counter
+        .stream_index
+        .checked_add(1)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream index incremented to {} 🧮",
+        stream_counter.stream_index
+    );
+
+    Ok(())
+}
+
+/// Context for creating a flow stream
+#[derive(Accounts)]
+pub struct CreateFlowStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        space = ANCHOR_DISCRIMINATOR + StreamCounter::INIT_SPACE,
+        payer = sender,
+        seeds = [FLOW_STREAM_COUNTER.as_ref(), sender.key().as_ref()],
+        bump,
+    )]
+    pub stream_counter: Account<'info, StreamCounter>,
+
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + FlowStream::INIT_SPACE,
+        payer = sender,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            sender.key().as_ref(),
+            &stream_counter.stream_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub stream: Account<'info, FlowStream>,
+
+    #[account(
+        init,
+        payer = sender,
+        token::mint = token_mint,
+        token::authority = treasury_token_account,
+        token::token_program = token_program,
+        seeds = [FLOW_TREASURY.as_ref(), stream.key().as_ref()],
+        bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    calculate_net_transfer_amount,
+    error::Error,
+    events,
+    seeds::{FLOW_STREAM, FLOW_TREASURY},
+    FlowStream,
+};
+
+/// Deposits tokens into a flow stream, covering its debt first. Anyone can deposit.
+///
+/// With a Token-2022 transfer fee, only what the treasury actually receives is added to the balance.
+pub fn process_deposit_into_flow_stream(
+    ctx: Context<DepositIntoFlowStream>,
+    amount: u64,
+) -> Result<()> {
+    msg!("Validating Deposit Operation... 🛂");
+    require!(amount > 0, Error::Validation::Stream::InvalidAmount);
+    require!(
+        !ctx.accounts.stream.is_voided,
+        Error::Validation::Flow::AlreadyVoided
+    );
+    msg!("Validation successful ✅ Depositing into flow stream... ⏳");
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.depositor_token_account.to_account_info(),
+        to: ctx.accounts.treasury_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    transfer_checked(cpi_ctx, amount, ctx.accounts.token_mint.decimals)?;
+    msg!("Transferred {} tokens to the treasury 💸", amount);
+
+    let received_amount =
+        calculate_net_transfer_amount(&ctx.accounts.token_mint.to_account_info(), amount)?;
+    let stream = &mut ctx.accounts.stream;
+    stream.balance = stream
+        .balance
+        .checked_add(received_amount)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    emit!(events::DepositIntoFlowStream {
+        stream: stream.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount: received_amount,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `deposit_into_flow_stream`
+#[derive(Accounts)]
+pub struct DepositIntoFlowStream<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, FlowStream>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        seeds = [FLOW_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
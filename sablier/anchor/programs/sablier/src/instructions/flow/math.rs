@@ -0,0 +1,116 @@
+use crate::{error::Error, FlowStream};
+use anchor_lang::prelude::*;
+
+/// Computes the total debt owed to the recipient of a flow stream at the given time.
+pub fn get_flow_total_debt(stream: &FlowStream, now: i64) -> Result<u64> {
+    calculate_flow_total_debt(
+        stream.snapshot_debt,
+        stream.snapshot_time,
+        stream.rate_per_second,
+        now,
+    )
+}
+
+/// Computes the part of the total debt that the balance covers, which the recipient can withdraw.
+pub fn get_flow_covered_debt(stream: &FlowStream, now: i64) -> Result<u64> {
+    Ok(get_flow_total_debt(stream, now)?.min(stream.balance))
+}
+
+/// Computes the part of the total debt that exceeds the balance.
+pub fn get_flow_uncovered_debt(stream: &FlowStream, now: i64) -> Result<u64> {
+    Ok(get_flow_total_debt(stream, now)?.saturating_sub(stream.balance))
+}
+
+/// Computes the part of the balance that is not owed to the recipient, which the sender can refund.
+pub fn get_flow_refundable_amount(stream: &FlowStream, now: i64) -> Result<u64> {
+    Ok(stream.balance - get_flow_covered_debt(stream, now)?)
+}
+
+/// Moves the debt accrued since the last snapshot into the snapshot debt.
+///
+/// Must be called before anything that affects the accrual, such as a rate change.
+pub fn snapshot_flow_stream(stream: &mut FlowStream, now: i64) -> Result<()> {
+    stream.snapshot_debt = get_flow_total_debt(stream, now)?;
+    stream.snapshot_time = now;
+
+    Ok(())
+}
+
+/// Adds the debt accrued at the given rate since the snapshot time to the snapshot debt.
+pub fn calculate_flow_total_debt(
+    snapshot_debt: u64,
+    snapshot_time: i64,
+    rate_per_second: u64,
+    now: i64,
+) -> Result<u64> {
+    let elapsed_time = now.saturating_sub(snapshot_time).max(0) as u128;
+
+    let ongoing_debt = (rate_per_second as u128)
+        .checked_mul(elapsed_time)
+        .and_then(|debt| debt.checked_add(snapshot_debt as u128))
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    u64::try_from(ongoing_debt).map_err(|_| error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_FLOW_RATE_PER_SECOND;
+
+    fn flow_stream(rate_per_second: u64, snapshot_debt: u64, balance: u64) -> FlowStream {
+        FlowStream {
+            index: 0,
+            bump: 0,
+            treasury_bump: 0,
+            name: "Test".to_string(),
+            sender: Pubkey::default(),
+            recipient: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            rate_per_second,
+            snapshot_time: 100,
+            snapshot_debt,
+            balance,
+            is_voided: false,
+        }
+    }
+
+    #[test]
+    fn accrues_debt_from_the_snapshot() {
+        assert_eq!(calculate_flow_total_debt(50, 100, 10, 99).unwrap(), 50);
+        assert_eq!(calculate_flow_total_debt(50, 100, 10, 100).unwrap(), 50);
+        assert_eq!(calculate_flow_total_debt(50, 100, 10, 110).unwrap(), 150);
+        assert!(calculate_flow_total_debt(0, 0, u64::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn accrues_debt_at_the_max_rate_for_a_century() {
+        let century = 100 * 365 * 24 * 60 * 60;
+        assert!(calculate_flow_total_debt(0, 0, MAX_FLOW_RATE_PER_SECOND, century).is_ok());
+    }
+
+    #[test]
+    fn splits_the_debt_between_covered_and_uncovered() {
+        let stream = flow_stream(10, 0, 300);
+
+        assert_eq!(get_flow_covered_debt(&stream, 120).unwrap(), 200);
+        assert_eq!(get_flow_uncovered_debt(&stream, 120).unwrap(), 0);
+        assert_eq!(get_flow_refundable_amount(&stream, 120).unwrap(), 100);
+
+        assert_eq!(get_flow_covered_debt(&stream, 150).unwrap(), 300);
+        assert_eq!(get_flow_uncovered_debt(&stream, 150).unwrap(), 200);
+        assert_eq!(get_flow_refundable_amount(&stream, 150).unwrap(), 0);
+    }
+
+    #[test]
+    fn keeps_the_debt_across_snapshots() {
+        let mut stream = flow_stream(10, 0, 1_000);
+
+        snapshot_flow_stream(&mut stream, 130).unwrap();
+        assert_eq!(stream.snapshot_debt, 300);
+
+        // Pausing stops the accrual without forgetting the debt
+        stream.rate_per_second = 0;
+        assert_eq!(get_flow_total_debt(&stream, 1_000).unwrap(), 300);
+    }
+}
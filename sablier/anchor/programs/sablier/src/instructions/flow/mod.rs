@@ -0,0 +1,26 @@
+pub use adjust_rate::*;
+pub mod adjust_rate;
+
+pub use create::*;
+pub mod create;
+
+pub use deposit::*;
+pub mod deposit;
+
+pub use math::*;
+pub mod math;
+
+pub use pause::*;
+pub mod pause;
+
+pub use refund::*;
+pub mod refund;
+
+pub use restart::*;
+pub mod restart;
+
+pub use void::*;
+pub mod void;
+
+pub use withdraw::*;
+pub mod withdraw;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::Error, events, seeds::FLOW_STREAM, snapshot_flow_stream, FlowStream};
+
+/// Pauses a flow stream, which stops accruing debt while keeping the debt accrued so far.
+pub fn process_pause_flow_stream(ctx: Context<PauseFlowStream>) -> Result<()> {
+    msg!("Validating Pause Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    require!(!stream.is_voided, Error::Validation::Flow::AlreadyVoided);
+    require!(
+        stream.rate_per_second > 0,
+        Error::Validation::Flow::AlreadyPaused
+    );
+    msg!("Validation successful ✅ Pausing flow stream... ⏳");
+
+    snapshot_flow_stream(stream, Clock::get()?.unix_timestamp)?;
+    stream.rate_per_second = 0;
+    msg!("Flow stream paused ⏸️");
+
+    emit!(events::PauseFlowStream {
+        stream: stream.key(),
+        total_debt: stream.snapshot_debt,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `pause_flow_stream`
+#[derive(Accounts)]
+pub struct PauseFlowStream<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump,
+        has_one = sender @ Error::Authorization::Flow::UnauthorizedSender,
+    )]
+    pub stream: Account<'info, FlowStream>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::Error,
+    events, get_flow_refundable_amount,
+    seeds::{FLOW_STREAM, FLOW_TREASURY},
+    transfer_from_flow_treasury, FlowStream,
+};
+
+/// Refunds part of a flow stream's balance that is not owed to the recipient back to the sender.
+pub fn process_refund_from_flow_stream(
+    ctx: Context<RefundFromFlowStream>,
+    amount: u64,
+) -> Result<()> {
+    msg!("Validating Refund Operation... 🛂");
+    require!(amount > 0, Error::Validation::Stream::InvalidAmount);
+
+    let stream = &mut ctx.accounts.stream;
+    let refundable_amount = get_flow_refundable_amount(stream, Clock::get()?.unix_timestamp)?;
+    msg!("Refundable Amount: {} 🏧", refundable_amount);
+    require!(
+        amount <= refundable_amount,
+        Error::Validation::Flow::ExceedsRefundable
+    );
+    msg!("Validation successful ✅ Refunding from flow stream... ⏳");
+
+    transfer_from_flow_treasury(
+        stream,
+        &ctx.accounts.treasury_token_account,
+        ctx.accounts.sender_token_account.to_account_info(),
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    stream.balance -= amount;
+
+    emit!(events::RefundFromFlowStream {
+        stream: stream.key(),
+        sender: stream.sender,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `refund_from_flow_stream`
+#[derive(Accounts)]
+pub struct RefundFromFlowStream<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump,
+        has_one = sender @ Error::Authorization::Flow::UnauthorizedSender,
+    )]
+    pub stream: Account<'info, FlowStream>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        seeds = [FLOW_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
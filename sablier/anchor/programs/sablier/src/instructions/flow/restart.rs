@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::Error, events, seeds::FLOW_STREAM, FlowStream, MAX_FLOW_RATE_PER_SECOND};
+
+/// Restarts a paused flow stream at the given rate, accruing debt again from now on.
+pub fn process_restart_flow_stream(
+    ctx: Context<RestartFlowStream>,
+    rate_per_second: u64,
+) -> Result<()> {
+    msg!("Validating Restart Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    require!(!stream.is_voided, Error::Validation::Flow::AlreadyVoided);
+    require!(
+        stream.rate_per_second == 0,
+        Error::Validation::Flow::NotPaused
+    );
+    require!(
+        rate_per_second > 0,
+        Error::Validation::Flow::InvalidRatePerSecond
+    );
+    require!(
+        rate_per_second <= MAX_FLOW_RATE_PER_SECOND,
+        Error::Validation::Flow::ExceedsMaxRatePerSecond
+    );
+    msg!("Validation successful ✅ Restarting flow stream... ⏳");
+
+    // Nothing accrued while paused, so the snapshot debt is already up to date
+    stream.snapshot_time = Clock::get()?.unix_timestamp;
+    stream.rate_per_second = rate_per_second;
+    msg!("Flow stream restarted at {} per second ▶️", rate_per_second);
+
+    emit!(events::RestartFlowStream {
+        stream: stream.key(),
+        rate_per_second,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `restart_flow_stream`
+#[derive(Accounts)]
+pub struct RestartFlowStream<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump,
+        has_one = sender @ Error::Authorization::Flow::UnauthorizedSender,
+    )]
+    pub stream: Account<'info, FlowStream>,
+}
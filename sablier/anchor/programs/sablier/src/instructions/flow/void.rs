@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::Error, events, get_flow_covered_debt, get_flow_uncovered_debt, seeds::FLOW_STREAM,
+    FlowStream,
+};
+
+/// Voids a flow stream for good, forgiving its uncovered debt and stopping any further accrual.
+///
+/// The recipient can still withdraw the covered debt and the sender can refund the rest afterwards.
+pub fn process_void_flow_stream(ctx: Context<VoidFlowStream>) -> Result<()> {
+    msg!("Validating Void Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    require!(!stream.is_voided, Error::Validation::Flow::AlreadyVoided);
+    msg!("Validation successful ✅ Voiding flow stream... ⏳");
+
+    let now = Clock::get()?.unix_timestamp;
+    let forgiven_debt = get_flow_uncovered_debt(stream, now)?;
+
+    stream.snapshot_debt = get_flow_covered_debt(stream, now)?;
+    stream.snapshot_time = now;
+    stream.rate_per_second = 0;
+    stream.is_voided = true;
+    msg!("Flow stream voided, forgiving {} of debt 🕳️", forgiven_debt);
+
+    emit!(events::VoidFlowStream {
+        stream: stream.key(),
+        signer: ctx.accounts.signer.key(),
+        forgiven_debt,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `void_flow_stream`
+#[derive(Accounts)]
+pub struct VoidFlowStream<'info> {
+    #[account(
+        constraint = signer.key() == stream.sender || signer.key() == stream.recipient
+            @ Error::Authorization::Flow::UnauthorizedVoid,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump,
+    )]
+    pub stream: Account<'info, FlowStream>,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    error::Error,
+    events, get_flow_covered_debt, get_flow_total_debt,
+    seeds::{FLOW_STREAM, FLOW_TREASURY},
+    FlowStream,
+};
+
+/// Withdraws up to the covered debt of a flow stream to its recipient. Anyone can trigger it.
+pub fn process_withdraw_from_flow_stream(
+    ctx: Context<WithdrawFromFlowStream>,
+    amount: u64,
+) -> Result<()> {
+    msg!("Validating Withdraw Operation... 🛂");
+    require!(amount > 0, Error::Validation::Stream::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let stream = &mut ctx.accounts.stream;
+
+    let total_debt = get_flow_total_debt(stream, now)?;
+    let covered_debt = get_flow_covered_debt(stream, now)?;
+    msg!(
+        "Total Debt: {} | Covered Debt: {} 🏧",
+        total_debt,
+        covered_debt
+    );
+    require!(
+        amount <= covered_debt,
+        Error::Validation::Flow::ExceedsWithdrawable
+    );
+    msg!("Validation successful ✅ Withdrawing from flow stream... ⏳");
+
+    transfer_from_flow_treasury(
+        stream,
+        &ctx.accounts.treasury_token_account,
+        ctx.accounts.recipient_token_account.to_account_info(),
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // Settle the withdrawn amount against the debt accrued so far
+    stream.snapshot_debt = total_debt - amount;
+    stream.snapshot_time = now;
+    stream.balance -= amount;
+
+    emit!(events::WithdrawFromFlowStream {
+        stream: stream.key(),
+        recipient: stream.recipient,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Transfers the given amount from a flow stream's treasury to the destination.
+pub(crate) fn transfer_from_flow_treasury<'info>(
+    stream: &Account<'info, FlowStream>,
+    treasury_token_account: &InterfaceAccount<'info, TokenAccount>,
+    destination_token_account: AccountInfo<'info>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from: treasury_token_account.to_account_info(),
+        to: destination_token_account,
+        mint: token_mint.to_account_info(),
+        authority: treasury_token_account.to_account_info(),
+    };
+
+    let stream_key = stream.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        FLOW_TREASURY.as_ref(),
+        stream_key.as_ref(),
+        &[stream.treasury_bump],
+    ]];
+
+    let cpi_ctx =
+        CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_ctx, amount, token_mint.decimals)?;
+    msg!("Transfer successful 💸");
+
+    Ok(())
+}
+
+/// Accounts for `withdraw_from_flow_stream`
+///
+/// Anyone can trigger the withdrawal, but the funds always go to the recipient's associated token account.
+#[derive(Accounts)]
+pub struct WithdrawFromFlowStream<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Verified against the stream's recipient
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FLOW_STREAM.as_ref(),
+            stream.sender.as_ref(),
+            &stream.index.to_le_bytes(),
+        ],
+        bump = stream.bump,
+        has_one = recipient,
+    )]
+    pub stream: Account<'info, FlowStream>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        seeds = [FLOW_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = token_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
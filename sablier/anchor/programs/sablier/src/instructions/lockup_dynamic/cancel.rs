@@ -0,0 +1,81 @@
+use crate::{
+    cancel_lockup_stream, get_lockup_dynamic_streamed_amount,
+    seeds::{LOCKUP_DYNAMIC_STREAM, LOCKUP_DYNAMIC_TREASURY},
+    validate_cancel, LockupDynamicStream, LockupTreasuryTransfer,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Cancels a lockup dynamic stream, refunding the amount that has not been streamed yet to the sender.
+pub fn process_cancel_lockup_dynamic_stream(ctx: Context<CancelLockupDynamicStream>) -> Result<()> {
+    msg!("Validating Cancel Operation... 🛂");
+    let now = Clock::get()?.unix_timestamp;
+    let base_stream = &ctx.accounts.stream.base_stream;
+    let status = base_stream.status(false, now);
+    validate_cancel(ctx.accounts.sender.key(), base_stream, status)?;
+    msg!("Validation successful ✅ Refunding sender... ⏳");
+
+    let streamed_amount = get_lockup_dynamic_streamed_amount(&ctx.accounts.stream, now)?;
+
+    let stream_key = ctx.accounts.stream.key();
+    let mint_key = ctx.accounts.token_mint.key();
+    let stream_counter_index = ctx.accounts.stream.base_stream.index.to_le_bytes();
+    let treasury_bump = [ctx.accounts.stream.base_stream.treasury_bump];
+
+    cancel_lockup_stream(
+        stream_key,
+        &mut ctx.accounts.stream.base_stream,
+        streamed_amount,
+        LockupTreasuryTransfer {
+            treasury_token_account: &ctx.accounts.treasury_token_account,
+            destination_token_account: &ctx.accounts.sender_token_account,
+            token_mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+            treasury_seeds: &[
+                LOCKUP_DYNAMIC_TREASURY.as_ref(),
+                mint_key.as_ref(),
+                &stream_counter_index,
+                &treasury_bump,
+            ],
+        },
+    )
+}
+
+/// Accounts for `cancel_lockup_dynamic_stream`
+#[derive(Accounts)]
+pub struct CancelLockupDynamicStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_DYNAMIC_STREAM.as_ref(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupDynamicStream>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_DYNAMIC_TREASURY.as_ref(),
+            token_mint.key().as_ref(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,179 @@
+use crate::{
+    error::Error,
+    events, fund_lockup_stream,
+    seeds::{LOCKUP_DYNAMIC_STREAM, LOCKUP_DYNAMIC_STREAM_COUNTER, LOCKUP_DYNAMIC_TREASURY},
+    validate_create_team_lockup_stream, Amounts, BaseStream, LockupDynamicStream, Segment,
+    StreamCounter, ANCHOR_DISCRIMINATOR, MAX_SEGMENT_COUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Creates a new lockup dynamic stream, transferring funds to a treasury account.
+pub fn process_create_lockup_dynamic_stream(
+    ctx: Context<CreateLockupDynamicStream>,
+    name: String,
+    recipient: Pubkey,
+    amount: u64,
+    start_time: i64,
+    segments: Vec<Segment>,
+    is_cancelable: bool,
+    is_transferable: bool,
+) -> Result<()> {
+    msg!("Validating Create Operation... 🛂");
+    validate_segments(start_time, amount, &segments)?;
+
+    // The stream ends at the last segment's milestone
+    let end_time = segments.last().unwrap().milestone;
+    validate_create_team_lockup_stream(
+        &ctx.accounts.stream_counter,
+        &ctx.accounts.token_mint.to_account_info(),
+        start_time,
+        end_time,
+        amount,
+    )?;
+
+    msg!("Validation successful ✅ Creating stream... ⏳");
+
+    let stream_counter = &mut ctx.accounts.stream_counter;
+    let stream_index = stream_counter.stream_index;
+
+    // Prepare amounts struct
+    let amounts = Amounts {
+        deposited: amount,
+        protocol_fee: 0,
+        refunded: 0,
+        withdrawn: 0,
+    };
+
+    // Initialize stream account
+    *ctx.accounts.stream = LockupDynamicStream {
+        base_stream: BaseStream {
+            index: stream_index,
+            bump: ctx.bumps.stream,
+            treasury_bump: ctx.bumps.treasury_token_account,
+            sender: *ctx.accounts.sender.key,
+            token_mint: ctx.accounts.token_mint.key(),
+            is_canceled: false,
+            name: name.clone(),
+            amounts,
+            start_time,
+            end_time,
+            is_cancelable,
+            is_transferable,
+            recipient,
+        },
+        segments,
+    };
+    msg!(
+        "LockupDynamicStream created with index: {} ✨",
+        stream_index
+    );
+    emit!(events::CreateLockupDynamicStream::new(
+        ctx.accounts.stream.key(),
+        &ctx.accounts.stream
+    ));
+
+    fund_lockup_stream(
+        &ctx.accounts.sender,
+        &ctx.accounts.sender_token_account,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // Increment stream counter
+    stream_counter.stream_index = stream_counter
+        .stream_index
+        .checked_add(1)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream index incremented to {} 🧮",
+        stream_counter.stream_index
+    );
+
+    Ok(())
+}
+
+/// Validates the segment count, milestone ordering and that the segments add up to the deposit.
+fn validate_segments(start_time: i64, amount: u64, segments: &[Segment]) -> Result<()> {
+    require!(
+        !segments.is_empty() && segments.len() <= MAX_SEGMENT_COUNT,
+        Error::Validation::Stream::InvalidSegmentCount
+    );
+
+    let mut previous_milestone = start_time;
+    let mut segment_amounts: u64 = 0;
+    for segment in segments {
+        require!(
+            segment.milestone > previous_milestone,
+            Error::Validation::Stream::InvalidSegmentMilestones
+        );
+        previous_milestone = segment.milestone;
+
+        segment_amounts = segment_amounts
+            .checked_add(segment.amount)
+            .ok_or(Error::Validation::Stream::InvalidSegmentAmounts)?;
+    }
+
+    require!(
+        segment_amounts == amount,
+        Error::Validation::Stream::InvalidSegmentAmounts
+    );
+
+    Ok(())
+}
+
+/// Context for creating a lockup dynamic stream
+#[derive(Accounts)]
+pub struct CreateLockupDynamicStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [LOCKUP_DYNAMIC_STREAM_COUNTER.as_ref()],
+        bump,
+    )]
+    pub stream_counter: Account<'info, StreamCounter>,
+
+    #[account(
+        init,
+        payer = sender,
+        token::mint = token_mint,
+        token::authority = treasury_token_account,
+        seeds = [
+            LOCKUP_DYNAMIC_TREASURY.as_ref(),
+            token_mint.key().as_ref(),
+            &stream_counter.stream_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + LockupDynamicStream::INIT_SPACE,
+        payer = sender,
+        seeds = [LOCKUP_DYNAMIC_STREAM.as_ref(), &stream_counter.stream_index.to_le_bytes()],
+        bump
+    )]
+    pub stream: Account<'info, LockupDynamicStream>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,19 +1,19 @@
 use crate::{
-    initialize_stream_counter, seeds::LOCKUP_LINEAR_STREAM_COUNTER, StreamCounter,
+    initialize_stream_counter, seeds::LOCKUP_DYNAMIC_STREAM_COUNTER, StreamCounter,
     ANCHOR_DISCRIMINATOR,
 };
 use anchor_lang::prelude::*;
 
-/// Initializes the lockup linear stream counter account.
-pub fn process_initialize_lockup_linear_stream_counter(
-    ctx: Context<InitializeLockupLinearStreamCounter>,
+/// Initializes the lockup dynamic stream counter account.
+pub fn process_initialize_lockup_dynamic_stream_counter(
+    ctx: Context<InitializeLockupDynamicStreamCounter>,
 ) -> Result<()> {
     initialize_stream_counter(ctx.accounts.sender.key(), &mut ctx.accounts.stream_counter)
 }
 
-/// Context for `initialize_lockup_linear_stream_counter`.
+/// Context for `initialize_lockup_dynamic_stream_counter`.
 #[derive(Accounts)]
-pub struct InitializeLockupLinearStreamCounter<'info> {
+pub struct InitializeLockupDynamicStreamCounter<'info> {
     #[account(mut)]
     pub sender: Signer<'info>,
 
@@ -21,7 +21,7 @@ pub struct InitializeLockupLinearStreamCounter<'info> {
         init,
         payer = sender,
         space = ANCHOR_DISCRIMINATOR + StreamCounter::INIT_SPACE,
-        seeds = [LOCKUP_LINEAR_STREAM_COUNTER.as_ref()],
+        seeds = [LOCKUP_DYNAMIC_STREAM_COUNTER.as_ref()],
         bump
     )]
     pub stream_counter: Account<'info, StreamCounter>,
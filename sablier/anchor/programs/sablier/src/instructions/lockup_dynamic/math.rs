@@ -0,0 +1,265 @@
+use crate::{error::Error, LockupDynamicStream, Segment, SEGMENT_EXPONENT_SCALE};
+use anchor_lang::prelude::*;
+
+/// Fixed-point scale used by the segment math, where `SCALE` represents 1.
+const SCALE: u128 = SEGMENT_EXPONENT_SCALE as u128;
+
+/// Natural logarithm of 2, scaled by `SCALE`.
+const LN_2: u128 = 693_147_180_559_945_309;
+
+/// Computes the amount streamed so far by summing the completed segments and
+/// applying the current segment's exponent to its elapsed time percentage.
+///
+/// Once a stream is canceled, the streamed amount is frozen at whatever was not refunded.
+pub fn get_lockup_dynamic_streamed_amount(stream: &LockupDynamicStream, now: i64) -> Result<u64> {
+    let base_stream = &stream.base_stream;
+
+    if base_stream.is_canceled {
+        return base_stream
+            .amounts
+            .deposited
+            .checked_sub(base_stream.amounts.refunded)
+            .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow));
+    }
+
+    if now <= base_stream.start_time {
+        return Ok(0);
+    }
+    if now >= base_stream.end_time {
+        return Ok(base_stream.amounts.deposited);
+    }
+
+    let mut previous_amounts: u64 = 0;
+    let mut segment_start_time = base_stream.start_time;
+
+    for segment in &stream.segments {
+        if now < segment.milestone {
+            let segment_streamed_amount =
+                calculate_segment_streamed_amount(segment, segment_start_time, now);
+
+            return previous_amounts
+                .checked_add(segment_streamed_amount)
+                .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow));
+        }
+
+        previous_amounts = previous_amounts
+            .checked_add(segment.amount)
+            .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))?;
+        segment_start_time = segment.milestone;
+    }
+
+    Ok(base_stream.amounts.deposited)
+}
+
+/// Computes the amount streamed by a segment that started at `start_time`, as
+/// `amount * (elapsed time percentage) ^ exponent`, rounded down.
+///
+/// The result never exceeds the segment amount.
+pub fn calculate_segment_streamed_amount(segment: &Segment, start_time: i64, now: i64) -> u64 {
+    if now <= start_time {
+        return 0;
+    }
+    if now >= segment.milestone {
+        return segment.amount;
+    }
+
+    let elapsed_time = now.abs_diff(start_time) as u128;
+    let total_time = segment.milestone.abs_diff(start_time) as u128;
+    let elapsed_percentage = elapsed_time * SCALE / total_time;
+
+    let streamed_amount =
+        segment.amount as u128 * pow(elapsed_percentage, segment.exponent as u128) / SCALE;
+
+    streamed_amount.min(segment.amount as u128) as u64
+}
+
+/// Raises `base` to the power of `exponent`, both scaled by `SCALE`, for a base between 0 and 1.
+///
+/// Computed as `2 ^ (exponent * log2(base))` using integer arithmetic only, so the result is
+/// deterministic and never exceeds 1.
+fn pow(base: u128, exponent: u128) -> u128 {
+    if exponent == 0 || base >= SCALE {
+        return SCALE;
+    }
+    if base == 0 {
+        return 0;
+    }
+
+    // log2(base) is negative, so work with log2(1 / base) instead
+    let log2_inverse = log2(SCALE * SCALE / base);
+    let power = log2_inverse * (exponent / SCALE) + log2_inverse * (exponent % SCALE) / SCALE;
+
+    inverse_exp2(power).min(SCALE)
+}
+
+/// Computes the binary logarithm of `x`, both scaled by `SCALE`, for an `x` of at least 1.
+///
+/// The integer part is the position of the most significant bit, and the fractional bits are
+/// found one at a time by repeatedly squaring the remainder.
+fn log2(x: u128) -> u128 {
+    let integer_part = 127 - (x / SCALE).leading_zeros();
+    let mut result = integer_part as u128 * SCALE;
+
+    let mut y = x >> integer_part;
+    let mut delta = SCALE / 2;
+    while delta > 0 && y != SCALE {
+        y = y * y / SCALE;
+        if y >= 2 * SCALE {
+            result += delta;
+            y >>= 1;
+        }
+        delta >>= 1;
+    }
+
+    result
+}
+
+/// Computes `2 ^ -x`, both scaled by `SCALE`.
+///
+/// The fractional part is evaluated as `e ^ (x * ln(2))` with its Taylor series, which converges
+/// quickly since the argument stays below `ln(2)`.
+fn inverse_exp2(x: u128) -> u128 {
+    let integer_part = x / SCALE;
+    if integer_part >= 64 {
+        return 0;
+    }
+
+    let argument = (x % SCALE) * LN_2 / SCALE;
+    let mut term = SCALE;
+    let mut sum = SCALE;
+    let mut k: u128 = 1;
+    while term > 0 {
+        term = term * argument / (SCALE * k);
+        sum += term;
+        k += 1;
+    }
+
+    (SCALE * SCALE / sum) >> integer_part
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Amounts, BaseStream};
+    use proptest::prelude::*;
+
+    fn segment(amount: u64, exponent: f64, milestone: i64) -> Segment {
+        Segment {
+            amount,
+            exponent: (exponent * SEGMENT_EXPONENT_SCALE as f64) as u64,
+            milestone,
+        }
+    }
+
+    fn lockup_dynamic_stream(start_time: i64, segments: Vec<Segment>) -> LockupDynamicStream {
+        LockupDynamicStream {
+            base_stream: BaseStream {
+                index: 0,
+                bump: 0,
+                treasury_bump: 0,
+                name: "Test".to_string(),
+                sender: Pubkey::default(),
+                recipient: Pubkey::default(),
+                token_mint: Pubkey::default(),
+                amounts: Amounts {
+                    deposited: segments.iter().map(|segment| segment.amount).sum(),
+                    protocol_fee: 0,
+                    refunded: 0,
+                    withdrawn: 0,
+                },
+                start_time,
+                end_time: segments.last().unwrap().milestone,
+                is_cancelable: true,
+                is_canceled: false,
+                is_transferable: true,
+            },
+            segments,
+        }
+    }
+
+    #[test]
+    fn raises_exact_powers_of_two() {
+        assert_eq!(pow(SCALE / 2, SCALE), SCALE / 2);
+        assert_eq!(pow(SCALE / 2, 2 * SCALE), SCALE / 4);
+        assert_eq!(pow(SCALE / 4, SCALE / 2), SCALE / 2);
+        assert_eq!(pow(SCALE / 2, 0), SCALE);
+        assert_eq!(pow(0, SCALE), 0);
+        assert_eq!(pow(SCALE, 3 * SCALE), SCALE);
+    }
+
+    #[test]
+    fn matches_floating_point_powers() {
+        for (base, exponent) in [
+            (0.3, 1.0),
+            (0.3, 2.5),
+            (0.75, 0.5),
+            (0.999, 18.0),
+            (0.01, 0.1),
+        ] {
+            let expected = f64::powf(base, exponent);
+            let actual = pow(
+                (base * SCALE as f64) as u128,
+                (exponent * SCALE as f64) as u128,
+            );
+            assert!((actual as f64 / SCALE as f64 - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn streams_each_segment_along_its_curve() {
+        let stream = lockup_dynamic_stream(
+            100,
+            vec![segment(1_000, 1.0, 200), segment(4_000, 2.0, 300)],
+        );
+
+        assert_eq!(get_lockup_dynamic_streamed_amount(&stream, 100).unwrap(), 0);
+        assert_eq!(
+            get_lockup_dynamic_streamed_amount(&stream, 150).unwrap(),
+            500
+        );
+        assert_eq!(
+            get_lockup_dynamic_streamed_amount(&stream, 200).unwrap(),
+            1_000
+        );
+        // A quarter of the second segment's time streams a sixteenth of its amount
+        assert_eq!(
+            get_lockup_dynamic_streamed_amount(&stream, 225).unwrap(),
+            1_250
+        );
+        assert_eq!(
+            get_lockup_dynamic_streamed_amount(&stream, 300).unwrap(),
+            5_000
+        );
+    }
+
+    #[test]
+    fn freezes_the_streamed_amount_once_canceled() {
+        let mut stream = lockup_dynamic_stream(100, vec![segment(1_000, 1.0, 200)]);
+        stream.base_stream.is_canceled = true;
+        stream.base_stream.amounts.refunded = 400;
+
+        assert_eq!(
+            get_lockup_dynamic_streamed_amount(&stream, 300).unwrap(),
+            600
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn segment_streamed_amount_is_monotonic_and_bounded(
+            amount in any::<u64>(),
+            exponent in any::<u64>(),
+            duration in 1i64..100_000_000,
+            first in 0i64..100_000_000,
+            second in 0i64..100_000_000,
+        ) {
+            let segment = Segment { amount, exponent, milestone: duration };
+            let (earlier, later) = (first.min(second), first.max(second));
+
+            let earlier_amount = calculate_segment_streamed_amount(&segment, 0, earlier);
+            let later_amount = calculate_segment_streamed_amount(&segment, 0, later);
+            prop_assert!(later_amount <= amount);
+            prop_assert!(earlier_amount <= later_amount);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{events, seeds::LOCKUP_DYNAMIC_STREAM, validate_renounce, LockupDynamicStream};
+
+/// Renounces the cancelability of a lockup dynamic stream.
+pub fn process_renounce_cancelability_lockup_dynamic_stream(
+    ctx: Context<RenounceCancelabilityLockupDynamicStream>,
+) -> Result<()> {
+    msg!("Validating Renounce Operation... 🛂");
+    let base_stream = &ctx.accounts.stream.base_stream;
+    let status = base_stream.status(false, Clock::get()?.unix_timestamp);
+    validate_renounce(ctx.accounts.sender.key(), base_stream, status)?;
+    msg!("Validation successful! ✅ Renouncing cancelability... ⏳");
+
+    let stream_key = ctx.accounts.stream.key();
+    let base_stream = &mut ctx.accounts.stream.base_stream;
+
+    // Mark the stream as no longer cancelable
+    base_stream.is_cancelable = false;
+
+    emit!(events::RenounceLockupStream {
+        stream: stream_key,
+        sender: base_stream.sender,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `renounce_cancelability_lockup_dynamic_stream`.
+#[derive(Accounts)]
+pub struct RenounceCancelabilityLockupDynamicStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_DYNAMIC_STREAM.as_ref(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupDynamicStream>,
+}
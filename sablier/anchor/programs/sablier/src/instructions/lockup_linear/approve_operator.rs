@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::Error, events, seeds::LOCKUP_LINEAR_STREAM, LockupLinearStream, OperatorApproval,
+};
+
+/// Approves an operator to withdraw from a lockup linear stream on behalf of its recipient.
+///
+/// Any previously approved operator is replaced.
+pub fn process_approve_lockup_linear_stream_operator(
+    ctx: Context<ApproveLockupLinearStreamOperator>,
+    operator: Pubkey,
+) -> Result<()> {
+    let recipient = ctx.accounts.recipient.key();
+    let stream = &mut ctx.accounts.stream;
+    stream.operator_approval = Some(OperatorApproval {
+        operator,
+        approver: recipient,
+    });
+    msg!("Operator {} approved 🤝", operator);
+
+    emit!(events::ApproveLockupStreamOperator {
+        stream: stream.key(),
+        recipient,
+        operator,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `approve_lockup_linear_stream_operator`
+#[derive(Accounts)]
+pub struct ApproveLockupLinearStreamOperator<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+
+    /// The recipient's token account holding the stream NFT, which grants the right to approve operators
+    #[account(
+        constraint = recipient_nft_token_account.mint == stream.nft_mint
+            && recipient_nft_token_account.owner == recipient.key()
+            && recipient_nft_token_account.amount == 1
+            @ Error::Authorization::Stream::UnauthorizedOperatorApproval,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
@@ -1,74 +1,74 @@
 use crate::{
-    extract_stream_counter_index,
-    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY},
-    validate_cancel, LockupLinearStream,
+    call_stream_hook, cancel_lockup_stream,
+    error::Error,
+    get_lockup_linear_status, get_lockup_linear_streamed_amount,
+    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY, STREAM_HOOK},
+    unpause_lockup_linear_stream, validate_cancel, CancelHookArgs, LockupLinearStream,
+    LockupTreasuryTransfer, CANCEL_HOOK,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-/// Cancels a lockup linear stream, refunding the appropriate amount to the sender.
-pub fn process_cancel_lockup_linear_stream(ctx: Context<CancelLockupLinearStream>) -> Result<()> {
+/// Cancels a lockup linear stream, refunding the amount that has not been streamed yet to the sender.
+///
+/// The streamed amount that has not been withdrawn yet stays in the treasury for the recipient.
+pub fn process_cancel_lockup_linear_stream<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelLockupLinearStream<'info>>,
+) -> Result<()> {
     msg!("Validating Cancel Operation... 🛂");
-    validate_cancel(ctx.accounts.sender.key(), &ctx.accounts.stream.base_stream)?;
-    msg!("Validation successful ✅ Refunding sender... ⏳");
-
-    let cliff_time = ctx.accounts.stream.cliff_time;
-    let base_stream = &ctx.accounts.stream.base_stream;
-    let start_time = base_stream.start_time;
-    let end_time = base_stream.end_time;
-    let deposited_amount = base_stream.amounts.deposited;
     let now = Clock::get()?.unix_timestamp;
 
-    // Calculate the amount to refund
-    let refundable_amount = if now < cliff_time {
-        msg!("Before cliff time, full amount will be refunded... 🏦");
-        deposited_amount
-    } else {
-        let not_elapsed_percentage =
-            (end_time as f64 - now as f64) / (end_time as f64 - start_time as f64);
-        (deposited_amount as f64 * not_elapsed_percentage) as u64
-    };
-    msg!("Refundable amount calculated: {} 💰", refundable_amount);
-
-    // Transfer the refundable amount
-    if refundable_amount > 0 {
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.treasury_token_account.to_account_info(),
-            to: ctx.accounts.sender_token_account.to_account_info(),
-            mint: ctx.accounts.token_mint.to_account_info(),
-            authority: ctx.accounts.treasury_token_account.to_account_info(),
-        };
-
-        let mint_key = ctx.accounts.token_mint.key();
-        let stream_counter_index =
-            extract_stream_counter_index(&ctx.accounts.stream.base_stream.id);
+    // A paused stream is canceled as if it was resumed right now, so the pause never counts towards its end
+    unpause_lockup_linear_stream(&mut ctx.accounts.stream, now)?;
+    validate_cancel(
+        ctx.accounts.sender.key(),
+        &ctx.accounts.stream.base_stream,
+        get_lockup_linear_status(&ctx.accounts.stream, now),
+    )?;
+    msg!("Validation successful ✅ Refunding sender... ⏳");
 
-        let signer_seeds: &[&[&[u8]]] = &[&[
-            LOCKUP_LINEAR_TREASURY.as_ref(),
-            mint_key.as_ref(),
-            &stream_counter_index,
-            &[ctx.bumps.treasury_token_account],
-        ]];
+    let streamed_amount = get_lockup_linear_streamed_amount(&ctx.accounts.stream, now)?;
 
-        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
-            .with_signer(signer_seeds);
-        transfer_checked(cpi_ctx, refundable_amount, ctx.accounts.token_mint.decimals)?;
-        msg!("Transfer successful 💸");
-    } else {
-        msg!("No amount to transfer 😟");
-    }
+    let stream_key = ctx.accounts.stream.key();
+    let treasury_bump = [ctx.accounts.stream.base_stream.treasury_bump];
 
-    // Mark stream as canceled
-    let stream = &mut ctx.accounts.stream;
-    let base_stream = &mut stream.base_stream;
-    base_stream.is_canceled = true;
-    base_stream.is_cancelable = false;
-    base_stream.amounts.refunded = refundable_amount;
-    msg!("Stream marked as canceled 🚫");
+    cancel_lockup_stream(
+        stream_key,
+        &mut ctx.accounts.stream.base_stream,
+        streamed_amount,
+        LockupTreasuryTransfer {
+            treasury_token_account: &ctx.accounts.treasury_token_account,
+            destination_token_account: &ctx.accounts.sender_token_account,
+            token_mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+            treasury_seeds: &[
+                LOCKUP_LINEAR_TREASURY.as_ref(),
+                stream_key.as_ref(),
+                &treasury_bump,
+            ],
+        },
+    )?;
 
-    Ok(())
+    // Notify the sender's hook program of the refund, if one is registered for the sender
+    let base_stream = &ctx.accounts.stream.base_stream;
+    let args = CancelHookArgs {
+        stream: stream_key,
+        stream_index: base_stream.index,
+        sender: base_stream.sender,
+        recipient: base_stream.recipient,
+        // A stream is only canceled once, so everything refunded was refunded just now
+        refunded_amount: base_stream.amounts.refunded,
+        recipient_amount: streamed_amount
+            .checked_sub(base_stream.amounts.withdrawn)
+            .ok_or(Error::Validation::Stream::ArithmeticOverflow)?,
+    };
+    call_stream_hook(
+        &ctx.accounts.sender_hook,
+        &ctx.accounts.stream,
+        ctx.remaining_accounts,
+        CANCEL_HOOK,
+        &args,
+    )
 }
 
 /// Accounts for `cancel_lockup_linear_stream`
@@ -81,20 +81,18 @@ pub struct CancelLockupLinearStream<'info> {
         mut,
         seeds = [
             LOCKUP_LINEAR_STREAM.as_ref(),
-            &extract_stream_counter_index(&stream.base_stream.id)
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
         ],
-        bump
+        bump = stream.base_stream.bump
     )]
     pub stream: Account<'info, LockupLinearStream>,
 
     #[account(
         mut,
-        seeds = [
-            LOCKUP_LINEAR_TREASURY.as_ref(),
-            token_mint.key().as_ref(),
-            &extract_stream_counter_index(&stream.base_stream.id),
-        ],
-        bump
+        token::mint = token_mint,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.base_stream.treasury_bump
     )]
     pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -106,6 +104,10 @@ pub struct CancelLockupLinearStream<'info> {
     )]
     pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: The sender's Stream Hook, which is only called back when it was registered
+    #[account(seeds = [STREAM_HOOK.as_ref(), sender.key().as_ref()], bump)]
+    pub sender_hook: UncheckedAccount<'info>,
+
     pub token_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
 }
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022_extensions::transfer_fee::{
+        harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint,
+    },
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    events, get_lockup_linear_status, get_withheld_transfer_fee,
+    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY},
+    validate_close, LockupLinearStream,
+};
+
+/// Closes a depleted lockup linear stream along with its treasury, returning the rent to whoever
+/// paid it, which is the claimant for streams claimed from a campaign.
+///
+/// Tokens donated to the treasury are swept to the sender, and Token-2022 transfer fees withheld in
+/// it are harvested to the mint, since the treasury could not be closed otherwise.
+///
+/// The NFT mint stays open, as the NFT keeps existing in its holder's wallet, so its rent is not
+/// reclaimed.
+pub fn process_close_lockup_linear_stream(ctx: Context<CloseLockupLinearStream>) -> Result<()> {
+    msg!("Validating Close Operation... 🛂");
+    let status = get_lockup_linear_status(&ctx.accounts.stream, Clock::get()?.unix_timestamp);
+    validate_close(
+        ctx.accounts.sender.key(),
+        &ctx.accounts.stream.base_stream,
+        status,
+    )?;
+    msg!("Validation successful ✅ Closing stream... ⏳");
+
+    let stream_key = ctx.accounts.stream.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LOCKUP_LINEAR_TREASURY.as_ref(),
+        stream_key.as_ref(),
+        &[ctx.accounts.stream.base_stream.treasury_bump],
+    ]];
+
+    // A depleted stream has no tokens left, so whatever the treasury still holds was donated
+    let donated_amount = ctx.accounts.treasury_token_account.amount;
+    if donated_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            to: ctx.accounts.sender_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            authority: ctx.accounts.treasury_token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+            .with_signer(signer_seeds);
+        transfer_checked(cpi_ctx, donated_amount, ctx.accounts.token_mint.decimals)?;
+        msg!("Swept {} donated tokens to the sender 💸", donated_amount);
+    }
+
+    let treasury_info = ctx.accounts.treasury_token_account.to_account_info();
+    if get_withheld_transfer_fee(&treasury_info)? > 0 {
+        let cpi_accounts = HarvestWithheldTokensToMint {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        harvest_withheld_tokens_to_mint(cpi_ctx, vec![treasury_info])?;
+        msg!("Withheld transfer fees harvested to the mint 🌾");
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.treasury_token_account.to_account_info(),
+        destination: ctx.accounts.rent_payer.to_account_info(),
+        authority: ctx.accounts.treasury_token_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+        .with_signer(signer_seeds);
+    close_account(cpi_ctx)?;
+    msg!("Treasury closed 🧹");
+
+    // The stream account itself is closed by Anchor once the instruction succeeds
+    emit!(events::CloseLockupStream {
+        stream: stream_key,
+        sender: ctx.accounts.stream.base_stream.sender,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `close_lockup_linear_stream`.
+#[derive(Accounts)]
+pub struct CloseLockupLinearStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: The account that paid the rent of the stream, which receives it back
+    #[account(mut, address = stream.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.base_stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receives the tokens donated to the treasury, if any
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mutable so that transfer fees withheld in the treasury can be harvested to it
+    #[account(mut)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
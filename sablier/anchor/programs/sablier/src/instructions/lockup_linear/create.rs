@@ -1,28 +1,156 @@
 use crate::{
+    calculate_broker_fee, calculate_lockup_linear_unlocked_amount, calculate_net_transfer_amount,
+    calculate_protocol_fee,
     error::Error,
-    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_STREAM_COUNTER, LOCKUP_LINEAR_TREASURY},
-    validate_create, Amounts, BaseStream, LockupLinearStream, StreamCounter, ANCHOR_DISCRIMINATOR,
+    events,
+    seeds::{
+        CONFIG, LOCKUP_LINEAR_NFT_MINT, LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_STREAM_COUNTER,
+        LOCKUP_LINEAR_TREASURY, PROTOCOL_FEE_VAULT,
+    },
+    validate_create, validate_token_mint, Amounts, BaseStream, Config, LockupLinearStream,
+    StreamCounter, ANCHOR_DISCRIMINATOR, LOCKUP_LINEAR_NFT_SYMBOL,
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
 };
-use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_2022::Token2022,
+    token_interface::{
+        freeze_account, mint_to, set_authority, spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_2022::instruction::AuthorityType,
+        spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+        transfer_checked, FreezeAccount, Mint, MintTo, SetAuthority, TokenAccount, TokenInterface,
+        TokenMetadataInitialize, TransferChecked,
+    },
 };
 
-/// Creates a new lockup linear stream, transferring funds to a treasury account.
+/// Creates a new lockup linear stream, transferring funds to a treasury account
+/// and minting the NFT that represents the stream to the recipient.
+///
+/// The protocol fee is taken out of the amount and sent to the fee vault of the token mint,
+/// and the optional broker fee is sent to the broker, so that only the remainder is deposited
+/// into the stream. With a Token-2022 transfer fee, the stream only records what the treasury
+/// actually receives.
+///
+/// The optional start and cliff unlocks are taken out of the deposit and become withdrawable at
+/// the start and cliff times respectively, while the rest of the deposit is streamed linearly.
 pub fn process_create_lockup_linear_stream(
     ctx: Context<CreateLockupLinearStream>,
     name: String,
-    recipient: Pubkey,
     amount: u64,
     start_time: i64,
     end_time: i64,
     cliff_time: i64,
     is_cancelable: bool,
     is_transferable: bool,
+    broker_fee_bps: u16,
+    start_unlock: Option<u64>,
+    cliff_unlock: Option<u64>,
 ) -> Result<()> {
     msg!("Validating Create Operation... 🛂");
-    validate_create(&ctx.accounts.stream_counter, start_time, end_time, amount)?;
+    let broker_fee = calculate_broker_fee(amount, broker_fee_bps)?;
+    require!(
+        broker_fee == 0 || ctx.accounts.broker_token_account.is_some(),
+        Error::Validation::Stream::MissingBrokerTokenAccount
+    );
+
+    // The counter is created on the sender's first stream
+    let stream_counter = &mut ctx.accounts.stream_counter;
+    stream_counter.authority = ctx.accounts.sender.key();
+
+    let (stream, deposited_amount) = build_lockup_linear_stream(
+        LockupLinearStreamParams {
+            name,
+            sender: ctx.accounts.sender.key(),
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            broker_fee,
+            start_time,
+            cliff_time,
+            end_time,
+            is_cancelable,
+            is_transferable,
+            start_unlock: start_unlock.unwrap_or_default(),
+            cliff_unlock: cliff_unlock.unwrap_or_default(),
+            rent_payer: ctx.accounts.sender.key(),
+        },
+        LockupLinearStreamAddresses {
+            index: stream_counter.stream_index,
+            bump: ctx.bumps.stream,
+            treasury_bump: ctx.bumps.treasury_token_account,
+            nft_mint: ctx.accounts.nft_mint.key(),
+        },
+        &ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.config.protocol_fee_bps,
+    )?;
+    *ctx.accounts.stream = stream;
+
+    fund_lockup_linear_stream(
+        FundLockupLinearStream {
+            funder: ctx.accounts.sender.to_account_info(),
+            funder_token_account: ctx.accounts.sender_token_account.to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            treasury_token_account: ctx.accounts.treasury_token_account.to_account_info(),
+            fee_vault_token_account: ctx.accounts.fee_vault_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            nft: MintLockupLinearStreamNft {
+                payer: ctx.accounts.sender.to_account_info(),
+                nft_mint: ctx.accounts.nft_mint.to_account_info(),
+                recipient_nft_token_account: ctx
+                    .accounts
+                    .recipient_nft_token_account
+                    .to_account_info(),
+                nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        },
+        ctx.accounts.stream.key(),
+        &ctx.accounts.stream,
+        deposited_amount,
+        ctx.accounts.token_mint.decimals,
+        ctx.bumps.nft_mint,
+        &[],
+    )?;
+
+    // Transfer the broker fee to the broker
+    if let Some(broker_token_account) = &ctx.accounts.broker_token_account {
+        if broker_fee > 0 {
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                to: broker_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            transfer_checked(cpi_ctx, broker_fee, ctx.accounts.token_mint.decimals)?;
+            msg!("Transferred {} tokens to the broker 🤝", broker_fee);
+        }
+    }
+
+    // Increment stream counter
+    stream_counter.stream_index = stream_counter
+        .stream_index
+        .checked_add(1)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream index incremented to {} 🧮",
+        stream_counter.stream_index
+    );
+
+    Ok(())
+}
+
+/// Validates the parameters of a lockup linear stream that is about to be created.
+pub(crate) fn validate_create_lockup_linear_stream(
+    amount: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+) -> Result<()> {
+    validate_create(start_time, end_time, amount)?;
 
     // Validate cliff time
     require!(
@@ -34,54 +162,289 @@ pub fn process_create_lockup_linear_stream(
         Error::Validation::Stream::InvalidCliffTime
     );
 
-    msg!("Validation successful ✅ Creating stream... ⏳");
+    Ok(())
+}
 
-    let stream_counter = &mut ctx.accounts.stream_counter;
-    let stream_id = format!("LL-{}", stream_counter.stream_index);
+/// Validates the amounts unlocked at the start and cliff times of a lockup linear stream.
+///
+/// The unlocks must fit within the deposit, and a cliff unlock needs a cliff that comes after the
+/// start time, since it would otherwise just be a start unlock.
+pub(crate) fn validate_lockup_linear_unlocks(
+    deposited_amount: u64,
+    start_unlock: u64,
+    cliff_unlock: u64,
+    start_time: i64,
+    cliff_time: i64,
+) -> Result<()> {
+    let unlocked_amount = calculate_lockup_linear_unlocked_amount(start_unlock, cliff_unlock)?;
+    require!(
+        unlocked_amount <= deposited_amount,
+        Error::Validation::Stream::InvalidUnlockAmounts
+    );
+    require!(
+        cliff_unlock == 0 || cliff_time > start_time,
+        Error::Validation::Stream::InvalidCliffUnlock
+    );
 
-    // Prepare amounts struct
-    let amounts = Amounts {
-        deposited: amount,
-        refunded: 0,
-        withdrawn: 0,
-    };
+    Ok(())
+}
+
+/// Parameters of a new lockup linear stream, whichever way it is created.
+pub(crate) struct LockupLinearStreamParams {
+    pub name: String,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    /// Amount paid for the stream, out of which the protocol and broker fees are taken.
+    pub amount: u64,
+    pub broker_fee: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub is_cancelable: bool,
+    pub is_transferable: bool,
+    pub start_unlock: u64,
+    pub cliff_unlock: u64,
+    pub rent_payer: Pubkey,
+}
 
-    // Initialize stream account
-    *ctx.accounts.stream = LockupLinearStream {
+/// Index and derived addresses of a new lockup linear stream.
+pub(crate) struct LockupLinearStreamAddresses {
+    pub index: u64,
+    pub bump: u8,
+    pub treasury_bump: u8,
+    pub nft_mint: Pubkey,
+}
+
+/// Validates the parameters of a new lockup linear stream and builds it.
+///
+/// The protocol fee is taken out of the amount, along with the broker fee, and the stream only
+/// records what its treasury actually receives. Returns the stream along with the amount to
+/// transfer into its treasury.
+pub(crate) fn build_lockup_linear_stream(
+    params: LockupLinearStreamParams,
+    addresses: LockupLinearStreamAddresses,
+    token_mint: &AccountInfo,
+    protocol_fee_bps: u16,
+) -> Result<(LockupLinearStream, u64)> {
+    validate_create_lockup_linear_stream(
+        params.amount,
+        params.start_time,
+        params.cliff_time,
+        params.end_time,
+    )?;
+    validate_token_mint(token_mint)?;
+
+    // Split the amount between the stream's deposit, the protocol fee and the broker fee
+    let protocol_fee = calculate_protocol_fee(params.amount, protocol_fee_bps)?;
+    let deposited_amount = params
+        .amount
+        .checked_sub(protocol_fee)
+        .and_then(|amount| amount.checked_sub(params.broker_fee))
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    let received_amount = calculate_net_transfer_amount(token_mint, deposited_amount)?;
+
+    validate_lockup_linear_unlocks(
+        received_amount,
+        params.start_unlock,
+        params.cliff_unlock,
+        params.start_time,
+        params.cliff_time,
+    )?;
+    msg!("Validation successful ✅ Creating stream... ⏳");
+
+    let stream = LockupLinearStream {
         base_stream: BaseStream {
-            id: stream_id.clone(),
-            sender: *ctx.accounts.sender.key,
-            token_mint: ctx.accounts.token_mint.key(),
+            index: addresses.index,
+            bump: addresses.bump,
+            treasury_bump: addresses.treasury_bump,
+            sender: params.sender,
+            token_mint: token_mint.key(),
             is_canceled: false,
-            name: name.clone(),
-            amounts,
-            start_time,
-            end_time,
-            is_cancelable,
-            is_transferable,
-            recipient,
+            name: params.name,
+            amounts: Amounts {
+                deposited: received_amount,
+                protocol_fee,
+                refunded: 0,
+                withdrawn: 0,
+            },
+            start_time: params.start_time,
+            end_time: params.end_time,
+            is_cancelable: params.is_cancelable,
+            is_transferable: params.is_transferable,
+            recipient: params.recipient,
         },
-        cliff_time,
+        cliff_time: params.cliff_time,
+        nft_mint: addresses.nft_mint,
+        paused_time: None,
+        paused_duration: 0,
+        operator_approval: None,
+        start_unlock: params.start_unlock,
+        cliff_unlock: params.cliff_unlock,
+        has_legacy_address: false,
+        rent_payer: params.rent_payer,
     };
-    msg!("LockupLinearStream created with ID: {} ✨", stream_id);
+
+    Ok((stream, deposited_amount))
+}
+
+/// Accounts needed to fund a newly created lockup linear stream and mint its NFT.
+pub(crate) struct FundLockupLinearStream<'info> {
+    /// Authority over `funder_token_account`, which is the sender or, for claimed streams, the
+    /// campaign's treasury.
+    pub funder: AccountInfo<'info>,
+    pub funder_token_account: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub treasury_token_account: AccountInfo<'info>,
+    pub fee_vault_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub nft: MintLockupLinearStreamNft<'info>,
+}
+
+/// Announces a newly created lockup linear stream, transfers the deposit into its treasury and
+/// the protocol fee into the fee vault, then mints the stream NFT to the recipient.
+///
+/// `funder_seeds` signs for the funder when it is a PDA, and is empty otherwise.
+pub(crate) fn fund_lockup_linear_stream(
+    accounts: FundLockupLinearStream,
+    stream_key: Pubkey,
+    stream: &LockupLinearStream,
+    deposited_amount: u64,
+    decimals: u8,
+    nft_mint_bump: u8,
+    funder_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    msg!(
+        "LockupLinearStream created with index: {} ✨",
+        stream.base_stream.index
+    );
+    emit!(events::CreateLockupLinearStream::new(stream_key, stream));
 
     // Transfer tokens into treasury
     let cpi_accounts = TransferChecked {
-        from: ctx.accounts.sender_token_account.to_account_info(),
-        to: ctx.accounts.treasury_token_account.to_account_info(),
-        mint: ctx.accounts.token_mint.to_account_info(),
-        authority: ctx.accounts.sender.to_account_info(),
+        from: accounts.funder_token_account.clone(),
+        to: accounts.treasury_token_account,
+        mint: accounts.token_mint.clone(),
+        authority: accounts.funder.clone(),
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
-    transfer_checked(cpi_ctx, amount, ctx.accounts.token_mint.decimals)?;
-    msg!("Transferred {} tokens to the treasury 💸", amount);
+    let cpi_ctx =
+        CpiContext::new_with_signer(accounts.token_program.clone(), cpi_accounts, funder_seeds);
+    transfer_checked(cpi_ctx, deposited_amount, decimals)?;
+    msg!("Transferred {} tokens to the treasury 💸", deposited_amount);
 
-    // Increment stream counter
-    stream_counter.stream_index += 1;
-    msg!(
-        "Stream index incremented to {} 🧮",
-        stream_counter.stream_index
-    );
+    // Transfer the protocol fee into the fee vault
+    let protocol_fee = stream.base_stream.amounts.protocol_fee;
+    if protocol_fee > 0 {
+        let cpi_accounts = TransferChecked {
+            from: accounts.funder_token_account,
+            to: accounts.fee_vault_token_account,
+            mint: accounts.token_mint,
+            authority: accounts.funder,
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(accounts.token_program, cpi_accounts, funder_seeds);
+        transfer_checked(cpi_ctx, protocol_fee, decimals)?;
+        msg!("Transferred {} tokens to the fee vault 🧾", protocol_fee);
+    }
+
+    mint_lockup_linear_stream_nft(accounts.nft, stream_key, stream, nft_mint_bump)
+}
+
+/// Accounts needed to mint the NFT representing a lockup linear stream.
+pub(crate) struct MintLockupLinearStreamNft<'info> {
+    /// Pays for the rent of the NFT metadata.
+    pub payer: AccountInfo<'info>,
+    pub nft_mint: AccountInfo<'info>,
+    pub recipient_nft_token_account: AccountInfo<'info>,
+    pub nft_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Mints the NFT representing the stream to the recipient and fixes its supply at 1.
+///
+/// The NFT is a Token-2022 mint whose metadata pointer refers to itself, so wallets display it
+/// with the stream's name and the `LOCKUP_LINEAR_NFT_SYMBOL` symbol.
+pub(crate) fn mint_lockup_linear_stream_nft(
+    accounts: MintLockupLinearStreamNft,
+    stream_key: Pubkey,
+    stream: &LockupLinearStream,
+    nft_mint_bump: u8,
+) -> Result<()> {
+    let MintLockupLinearStreamNft {
+        payer,
+        nft_mint,
+        recipient_nft_token_account,
+        nft_token_program,
+        system_program,
+    } = accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LOCKUP_LINEAR_NFT_MINT.as_ref(),
+        stream_key.as_ref(),
+        &[nft_mint_bump],
+    ]];
+
+    // Top up the rent of the mint, which grows to hold the metadata
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(nft_mint.key()),
+        mint: nft_mint.key(),
+        name: stream.base_stream.name.clone(),
+        symbol: LOCKUP_LINEAR_NFT_SYMBOL.to_string(),
+        ..Default::default()
+    };
+    let space = nft_mint.data_len() + metadata.tlv_size_of()?;
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(nft_mint.lamports());
+    if rent_shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: payer,
+            to: nft_mint.clone(),
+        };
+        transfer(
+            CpiContext::new(system_program, cpi_accounts),
+            rent_shortfall,
+        )?;
+    }
+
+    let cpi_accounts = TokenMetadataInitialize {
+        token_program_id: nft_token_program.clone(),
+        metadata: nft_mint.clone(),
+        update_authority: nft_mint.clone(),
+        mint_authority: nft_mint.clone(),
+        mint: nft_mint.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new(nft_token_program.clone(), cpi_accounts).with_signer(signer_seeds);
+    token_metadata_initialize(cpi_ctx, metadata.name, metadata.symbol, metadata.uri)?;
+
+    let cpi_accounts = MintTo {
+        mint: nft_mint.clone(),
+        to: recipient_nft_token_account.clone(),
+        authority: nft_mint.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new(nft_token_program.clone(), cpi_accounts).with_signer(signer_seeds);
+    mint_to(cpi_ctx, 1)?;
+
+    // Non-transferable streams have their NFT frozen in the recipient's wallet
+    if !stream.base_stream.is_transferable {
+        let cpi_accounts = FreezeAccount {
+            account: recipient_nft_token_account,
+            mint: nft_mint.clone(),
+            authority: nft_mint.clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new(nft_token_program.clone(), cpi_accounts).with_signer(signer_seeds);
+        freeze_account(cpi_ctx)?;
+    }
+
+    // Revoke the mint authority so that the supply stays fixed at 1
+    let cpi_accounts = SetAuthority {
+        current_authority: nft_mint.clone(),
+        account_or_mint: nft_mint,
+    };
+    let cpi_ctx = CpiContext::new(nft_token_program, cpi_accounts).with_signer(signer_seeds);
+    set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+    msg!("Stream NFT minted to the recipient 🖼️");
 
     Ok(())
 }
@@ -92,6 +455,9 @@ pub struct CreateLockupLinearStream<'info> {
     #[account(mut)]
     pub sender: Signer<'info>,
 
+    /// CHECK: The recipient only receives the stream NFT, any account is allowed.
+    pub recipient: UncheckedAccount<'info>,
+
     pub token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
@@ -103,36 +469,88 @@ pub struct CreateLockupLinearStream<'info> {
     pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
-        mut,
-        seeds = [LOCKUP_LINEAR_STREAM_COUNTER.as_ref()],
+        init_if_needed,
+        space = ANCHOR_DISCRIMINATOR + StreamCounter::INIT_SPACE,
+        payer = sender,
+        seeds = [LOCKUP_LINEAR_STREAM_COUNTER.as_ref(), sender.key().as_ref()],
         bump,
     )]
     pub stream_counter: Account<'info, StreamCounter>,
 
     #[account(
-        init,
+        seeds = [CONFIG.as_ref()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
         payer = sender,
         token::mint = token_mint,
-        token::authority = treasury_token_account,
+        token::authority = fee_vault_token_account,
+        token::token_program = token_program,
+        seeds = [PROTOCOL_FEE_VAULT.as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE,
+        payer = sender,
         seeds = [
-            LOCKUP_LINEAR_TREASURY.as_ref(),
-            token_mint.key().as_ref(),
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            sender.key().as_ref(),
             &stream_counter.stream_index.to_le_bytes()
         ],
         bump
     )]
+    pub stream: Account<'info, LockupLinearStream>,
+
+    #[account(
+        init,
+        payer = sender,
+        token::mint = token_mint,
+        token::authority = treasury_token_account,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump
+    )]
     pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init,
-        space = ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE,
         payer = sender,
-        seeds = [LOCKUP_LINEAR_STREAM.as_ref(), &stream_counter.stream_index.to_le_bytes()],
+        mint::decimals = 0,
+        mint::authority = nft_mint,
+        mint::freeze_authority = nft_mint,
+        mint::token_program = nft_token_program,
+        extensions::metadata_pointer::authority = nft_mint,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+        seeds = [LOCKUP_LINEAR_NFT_MINT.as_ref(), stream.key().as_ref()],
         bump
     )]
-    pub stream: Account<'info, LockupLinearStream>,
+    pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = sender,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = nft_token_program,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account of the broker (e.g. an integrator UI) that receives the broker fee
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+    )]
+    pub broker_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
+    /// Program of the stream NFT, which holds its metadata as a Token-2022 extension
+    pub nft_token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,405 @@
+use crate::{
+    build_lockup_linear_stream,
+    error::Error,
+    fund_lockup_linear_stream,
+    seeds::{
+        CONFIG, LOCKUP_LINEAR_NFT_MINT, LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_STREAM_COUNTER,
+        LOCKUP_LINEAR_TREASURY, PROTOCOL_FEE_VAULT,
+    },
+    validate_token_mint, Config, FundLockupLinearStream, LockupLinearStream,
+    LockupLinearStreamAddresses, LockupLinearStreamParams, MintLockupLinearStreamNft,
+    StreamCounter, ANCHOR_DISCRIMINATOR,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::program_pack::Pack,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
+    token_2022::{
+        spl_token_2022::{
+            extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+            state,
+        },
+        Token2022,
+    },
+    token_interface::{
+        initialize_account3, initialize_mint2, metadata_pointer_initialize, InitializeAccount3,
+        InitializeMint2, MetadataPointerInitialize, Mint, TokenAccount, TokenInterface,
+    },
+};
+
+/// Number of remaining accounts expected for each entry of the batch:
+/// `[stream, treasury_token_account, nft_mint, recipient, recipient_nft_token_account]`
+pub const BATCH_ENTRY_ACCOUNT_COUNT: usize = 5;
+
+/// Parameters of a single stream within a batch creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateLockupLinearStreamEntry {
+    pub name: String,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub is_cancelable: bool,
+    pub is_transferable: bool,
+}
+
+/// Creates a lockup linear stream for each entry, along with its treasury and NFT.
+///
+/// The protocol fee is charged on every entry, as for single stream creation.
+///
+/// The accounts of every entry are passed through `remaining_accounts`, in the order given by
+/// `BATCH_ENTRY_ACCOUNT_COUNT`, and the sender's stream counter is incremented once per entry.
+pub fn process_create_lockup_linear_streams_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateLockupLinearStreamsBatch<'info>>,
+    entries: Vec<CreateLockupLinearStreamEntry>,
+) -> Result<()> {
+    msg!("Validating Batch Create Operation... 🛂");
+    require!(!entries.is_empty(), Error::Validation::Stream::EmptyBatch);
+    validate_token_mint(&ctx.accounts.token_mint.to_account_info())?;
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * BATCH_ENTRY_ACCOUNT_COUNT,
+        Error::Validation::Stream::InvalidBatchAccounts
+    );
+    msg!(
+        "Validation successful ✅ Creating {} streams... ⏳",
+        entries.len()
+    );
+
+    // The counter is created on the sender's first stream
+    ctx.accounts.stream_counter.authority = ctx.accounts.sender.key();
+
+    for (entry, entry_accounts) in entries
+        .into_iter()
+        .zip(ctx.remaining_accounts.chunks(BATCH_ENTRY_ACCOUNT_COUNT))
+    {
+        create_lockup_linear_stream_entry(ctx.accounts, entry, entry_accounts)?;
+    }
+
+    Ok(())
+}
+
+/// Creates the accounts of a single batch entry and funds its stream.
+fn create_lockup_linear_stream_entry<'info>(
+    accounts: &mut CreateLockupLinearStreamsBatch<'info>,
+    entry: CreateLockupLinearStreamEntry,
+    entry_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let [stream, treasury_token_account, nft_mint, recipient, recipient_nft_token_account] =
+        entry_accounts
+    else {
+        return err!(Error::Validation::Stream::InvalidBatchAccounts);
+    };
+
+    require_keys_eq!(
+        recipient.key(),
+        entry.recipient,
+        Error::Validation::Stream::InvalidBatchAccounts
+    );
+
+    let stream_index = accounts.stream_counter.stream_index;
+    let stream_counter_index = stream_index.to_le_bytes();
+    let sender_key = accounts.sender.key();
+    let stream_key = stream.key();
+    let token_program_key = accounts.token_program.key();
+
+    // Create the stream account
+    let stream_bump = find_entry_pda(
+        stream,
+        &[
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            sender_key.as_ref(),
+            &stream_counter_index,
+        ],
+    )?;
+    create_entry_account(
+        accounts,
+        stream,
+        ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE,
+        &crate::ID,
+        &[
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            sender_key.as_ref(),
+            &stream_counter_index,
+            &[stream_bump],
+        ],
+    )?;
+
+    // Create the treasury token account, owned by itself
+    let treasury_bump = find_entry_pda(
+        treasury_token_account,
+        &[LOCKUP_LINEAR_TREASURY.as_ref(), stream_key.as_ref()],
+    )?;
+    let treasury_space = get_token_account_space(&accounts.token_mint.to_account_info())?;
+    create_entry_account(
+        accounts,
+        treasury_token_account,
+        treasury_space,
+        &token_program_key,
+        &[
+            LOCKUP_LINEAR_TREASURY.as_ref(),
+            stream_key.as_ref(),
+            &[treasury_bump],
+        ],
+    )?;
+    let cpi_accounts = InitializeAccount3 {
+        account: treasury_token_account.clone(),
+        mint: accounts.token_mint.to_account_info(),
+        authority: treasury_token_account.clone(),
+    };
+    initialize_account3(CpiContext::new(
+        accounts.token_program.to_account_info(),
+        cpi_accounts,
+    ))?;
+
+    // Create the stream NFT mint, which is its own mint and freeze authority and holds its own
+    // metadata
+    let nft_mint_bump = find_entry_pda(
+        nft_mint,
+        &[LOCKUP_LINEAR_NFT_MINT.as_ref(), stream_key.as_ref()],
+    )?;
+    create_entry_account(
+        accounts,
+        nft_mint,
+        ExtensionType::try_calculate_account_len::<state::Mint>(&[ExtensionType::MetadataPointer])?,
+        &Token2022::id(),
+        &[
+            LOCKUP_LINEAR_NFT_MINT.as_ref(),
+            stream_key.as_ref(),
+            &[nft_mint_bump],
+        ],
+    )?;
+    let cpi_accounts = MetadataPointerInitialize {
+        token_program_id: accounts.nft_token_program.to_account_info(),
+        mint: nft_mint.clone(),
+    };
+    metadata_pointer_initialize(
+        CpiContext::new(accounts.nft_token_program.to_account_info(), cpi_accounts),
+        Some(nft_mint.key()),
+        Some(nft_mint.key()),
+    )?;
+    let cpi_accounts = InitializeMint2 {
+        mint: nft_mint.clone(),
+    };
+    initialize_mint2(
+        CpiContext::new(accounts.nft_token_program.to_account_info(), cpi_accounts),
+        0,
+        nft_mint.key,
+        Some(nft_mint.key),
+    )?;
+
+    // Create the recipient's NFT token account
+    require_keys_eq!(
+        recipient_nft_token_account.key(),
+        get_associated_token_address_with_program_id(recipient.key, nft_mint.key, &Token2022::id()),
+        Error::Validation::Stream::InvalidBatchAccounts
+    );
+    let cpi_accounts = associated_token::Create {
+        payer: accounts.sender.to_account_info(),
+        associated_token: recipient_nft_token_account.clone(),
+        authority: recipient.clone(),
+        mint: nft_mint.clone(),
+        system_program: accounts.system_program.to_account_info(),
+        token_program: accounts.nft_token_program.to_account_info(),
+    };
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        cpi_accounts,
+    ))?;
+
+    let (stream_data, deposited_amount) = build_lockup_linear_stream(
+        LockupLinearStreamParams {
+            name: entry.name,
+            sender: sender_key,
+            recipient: entry.recipient,
+            amount: entry.amount,
+            broker_fee: 0,
+            start_time: entry.start_time,
+            cliff_time: entry.cliff_time,
+            end_time: entry.end_time,
+            is_cancelable: entry.is_cancelable,
+            is_transferable: entry.is_transferable,
+            start_unlock: 0,
+            cliff_unlock: 0,
+            rent_payer: sender_key,
+        },
+        LockupLinearStreamAddresses {
+            index: stream_index,
+            bump: stream_bump,
+            treasury_bump,
+            nft_mint: nft_mint.key(),
+        },
+        &accounts.token_mint.to_account_info(),
+        accounts.config.protocol_fee_bps,
+    )?;
+    stream_data.try_serialize(&mut &mut stream.try_borrow_mut_data()?[..])?;
+
+    fund_lockup_linear_stream(
+        FundLockupLinearStream {
+            funder: accounts.sender.to_account_info(),
+            funder_token_account: accounts.sender_token_account.to_account_info(),
+            token_mint: accounts.token_mint.to_account_info(),
+            treasury_token_account: treasury_token_account.clone(),
+            fee_vault_token_account: accounts.fee_vault_token_account.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            nft: MintLockupLinearStreamNft {
+                payer: accounts.sender.to_account_info(),
+                nft_mint: nft_mint.clone(),
+                recipient_nft_token_account: recipient_nft_token_account.clone(),
+                nft_token_program: accounts.nft_token_program.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+            },
+        },
+        stream_key,
+        &stream_data,
+        deposited_amount,
+        accounts.token_mint.decimals,
+        nft_mint_bump,
+        &[],
+    )?;
+
+    // Increment stream counter
+    accounts.stream_counter.stream_index = accounts
+        .stream_counter
+        .stream_index
+        .checked_add(1)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream index incremented to {} 🧮",
+        accounts.stream_counter.stream_index
+    );
+
+    Ok(())
+}
+
+/// Checks that the given account is the PDA derived from the seeds and returns its bump.
+fn find_entry_pda(account: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(
+        account.key(),
+        address,
+        Error::Validation::Stream::InvalidBatchAccounts
+    );
+
+    Ok(bump)
+}
+
+/// Creates a rent-exempt PDA owned by the given program, paid for by the sender.
+///
+/// As with Anchor's `init`, a PDA that already holds lamports is topped up to rent exemption, then
+/// allocated and assigned, so that sending lamports to a predictable address cannot block a batch.
+fn create_entry_account<'info>(
+    accounts: &CreateLockupLinearStreamsBatch<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let signer_seeds = &[signer_seeds];
+    let system_program = accounts.system_program.to_account_info();
+
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        let cpi_accounts = CreateAccount {
+            from: accounts.sender.to_account_info(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program, cpi_accounts).with_signer(signer_seeds);
+
+        return create_account(cpi_ctx, rent_lamports, space as u64, owner);
+    }
+
+    let required_lamports = rent_lamports.max(1).saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        let cpi_accounts = Transfer {
+            from: accounts.sender.to_account_info(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        transfer(cpi_ctx, required_lamports)?;
+    }
+
+    let cpi_accounts = Allocate {
+        account_to_allocate: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts).with_signer(signer_seeds);
+    allocate(cpi_ctx, space as u64)?;
+
+    let cpi_accounts = Assign {
+        account_to_assign: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new(system_program, cpi_accounts).with_signer(signer_seeds);
+    assign(cpi_ctx, owner)
+}
+
+/// Computes the size of a token account for the given mint, including the extensions that
+/// Token-2022 mints require on their token accounts.
+fn get_token_account_space(mint: &AccountInfo) -> Result<usize> {
+    if *mint.owner != Token2022::id() {
+        return Ok(state::Account::LEN);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<state::Mint>::unpack(&mint_data)?;
+    let required_extensions =
+        ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+
+    Ok(ExtensionType::try_calculate_account_len::<state::Account>(
+        &required_extensions,
+    )?)
+}
+
+/// Context for creating a batch of lockup linear streams
+#[derive(Accounts)]
+pub struct CreateLockupLinearStreamsBatch<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        space = ANCHOR_DISCRIMINATOR + StreamCounter::INIT_SPACE,
+        payer = sender,
+        seeds = [LOCKUP_LINEAR_STREAM_COUNTER.as_ref(), sender.key().as_ref()],
+        bump,
+    )]
+    pub stream_counter: Account<'info, StreamCounter>,
+
+    #[account(
+        seeds = [CONFIG.as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        token::mint = token_mint,
+        token::authority = fee_vault_token_account,
+        token::token_program = token_program,
+        seeds = [PROTOCOL_FEE_VAULT.as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Program of the stream NFTs, which hold their metadata as a Token-2022 extension
+    pub nft_token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,618 @@
+use crate::{error::Error, LockupLinearStream, StreamStatus};
+use anchor_lang::prelude::*;
+
+/// Computes the amount streamed by a lockup linear stream at the given time.
+///
+/// Once a stream is canceled, the streamed amount is frozen at whatever was not refunded to the
+/// sender, so that the recipient can still withdraw their remaining share. While a stream is
+/// paused, the streamed amount is frozen at the time of the pause.
+///
+/// The start unlock is streamed at the start time and the cliff unlock at the cliff time, on top
+/// of the linearly streamed remainder of the deposit.
+pub fn get_lockup_linear_streamed_amount(stream: &LockupLinearStream, now: i64) -> Result<u64> {
+    let base_stream = &stream.base_stream;
+
+    if base_stream.is_canceled {
+        return base_stream
+            .amounts
+            .deposited
+            .checked_sub(base_stream.amounts.refunded)
+            .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow));
+    }
+
+    let now = match stream.paused_time {
+        Some(paused_time) => now.min(paused_time),
+        None => now,
+    };
+
+    if now < base_stream.start_time {
+        return Ok(0);
+    }
+
+    let unlocked_amount = if now >= stream.cliff_time {
+        calculate_lockup_linear_unlocked_amount(stream.start_unlock, stream.cliff_unlock)?
+    } else {
+        stream.start_unlock
+    };
+    let linear_amount = calculate_lockup_linear_streamed_amount(
+        get_lockup_linear_streamable_amount(stream)?,
+        base_stream.start_time,
+        stream.cliff_time,
+        base_stream.end_time,
+        now,
+    )?;
+
+    unlocked_amount
+        .checked_add(linear_amount)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the part of a lockup linear stream's deposit that is streamed linearly, i.e. everything
+/// but its start and cliff unlocks.
+pub fn get_lockup_linear_streamable_amount(stream: &LockupLinearStream) -> Result<u64> {
+    stream
+        .base_stream
+        .amounts
+        .deposited
+        .checked_sub(stream.start_unlock)
+        .and_then(|amount| amount.checked_sub(stream.cliff_unlock))
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the total amount unlocked by a lockup linear stream's start and cliff unlocks.
+pub fn calculate_lockup_linear_unlocked_amount(
+    start_unlock: u64,
+    cliff_unlock: u64,
+) -> Result<u64> {
+    start_unlock
+        .checked_add(cliff_unlock)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Ends the pause of a lockup linear stream and returns how long it lasted.
+///
+/// The start, cliff and end times are pushed back by the pause's duration, so that the time spent
+/// paused is excluded from the elapsed time and the streamed amount picks up where it was frozen.
+pub fn unpause_lockup_linear_stream(stream: &mut LockupLinearStream, now: i64) -> Result<i64> {
+    let Some(paused_time) = stream.paused_time.take() else {
+        return Ok(0);
+    };
+
+    let pause_duration = now.saturating_sub(paused_time).max(0);
+    let shift = |time: i64| {
+        time.checked_add(pause_duration)
+            .ok_or(Error::Validation::Stream::ArithmeticOverflow)
+    };
+
+    let base_stream = &mut stream.base_stream;
+    base_stream.start_time = shift(base_stream.start_time)?;
+    base_stream.end_time = shift(base_stream.end_time)?;
+    stream.cliff_time = shift(stream.cliff_time)?;
+    stream.paused_duration = shift(stream.paused_duration)?;
+
+    Ok(pause_duration)
+}
+
+/// Computes the amount that the recipient can withdraw from a lockup linear stream at the given time.
+pub fn get_lockup_linear_withdrawable_amount(stream: &LockupLinearStream, now: i64) -> Result<u64> {
+    get_lockup_linear_streamed_amount(stream, now)?
+        .checked_sub(stream.base_stream.amounts.withdrawn)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the amount that would be refunded to the sender if the lockup linear stream was canceled at the given time.
+///
+/// Streams that cannot be canceled anymore have nothing to refund. A paused stream is resumed
+/// first, as cancel does, so that its pause pushes the end time back.
+pub fn get_lockup_linear_refundable_amount(stream: &LockupLinearStream, now: i64) -> Result<u64> {
+    let mut stream = stream.clone();
+    unpause_lockup_linear_stream(&mut stream, now)?;
+
+    let base_stream = &stream.base_stream;
+    if !base_stream.is_cancelable || base_stream.is_canceled || now >= base_stream.end_time {
+        return Ok(0);
+    }
+
+    base_stream
+        .amounts
+        .deposited
+        .checked_sub(get_lockup_linear_streamed_amount(&stream, now)?)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the status of a lockup linear stream at the given time.
+pub fn get_lockup_linear_status(stream: &LockupLinearStream, now: i64) -> StreamStatus {
+    stream.base_stream.status(stream.paused_time.is_some(), now)
+}
+
+/// Computes the linearly streamed amount using integer-only u128 arithmetic.
+///
+/// Nothing is streamed before the cliff, after which the amount grows linearly from the start
+/// time until the whole deposit is streamed at the end time. The result is rounded down so that
+/// withdrawals and refunds can never add up to more than the deposit.
+pub fn calculate_lockup_linear_streamed_amount(
+    deposited: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+    now: i64,
+) -> Result<u64> {
+    if now < cliff_time || now < start_time {
+        return Ok(0);
+    }
+    if now >= end_time {
+        return Ok(deposited);
+    }
+
+    let elapsed_time = now
+        .checked_sub(start_time)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)? as u128;
+    let total_time = end_time
+        .checked_sub(start_time)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)? as u128;
+
+    let streamed_amount = (deposited as u128)
+        .checked_mul(elapsed_time)
+        .and_then(|amount| amount.checked_div(total_time))
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    u64::try_from(streamed_amount)
+        .map_err(|_| error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the end time that keeps the stream's rate unchanged once `amount` is added to its deposit.
+///
+/// The extension is rounded down, so that the rate can only grow slightly and the amount streamed
+/// at any time is never lower than before the top-up.
+///
+/// A stream whose deposit is entirely made of unlocks has no rate to keep, so it cannot be extended.
+pub fn calculate_extended_end_time(
+    deposited: u64,
+    start_time: i64,
+    end_time: i64,
+    amount: u64,
+) -> Result<i64> {
+    require!(deposited > 0, Error::Validation::Stream::NoStreamableAmount);
+
+    let total_time = end_time
+        .checked_sub(start_time)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)? as u128;
+
+    let extension = (amount as u128)
+        .checked_mul(total_time)
+        .and_then(|extension| extension.checked_div(deposited as u128))
+        .and_then(|extension| i64::try_from(extension).ok())
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    end_time
+        .checked_add(extension)
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+/// Computes the start time that spreads `amount` over the remaining period of the stream once it
+/// is added to its deposit, without changing the end time.
+///
+/// The start time is moved forward just enough for the new linear schedule to go through the
+/// amount streamed so far, regardless of the cliff, so that the recipient never loses what has
+/// already been streamed. It is never moved before the original start time.
+pub fn calculate_rebased_start_time(
+    deposited: u64,
+    start_time: i64,
+    end_time: i64,
+    amount: u64,
+    now: i64,
+) -> Result<i64> {
+    if now <= start_time {
+        return Ok(start_time);
+    }
+
+    let streamed_amount =
+        calculate_lockup_linear_streamed_amount(deposited, start_time, start_time, end_time, now)?
+            as u128;
+    let remaining_time = end_time
+        .checked_sub(now)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)? as u128;
+    let new_deposited = (deposited as u128)
+        .checked_add(amount as u128)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    // Rounding the elapsed time up keeps the new schedule at or above the amount streamed so far
+    let elapsed_time = streamed_amount
+        .checked_mul(remaining_time)
+        .and_then(|elapsed| {
+            let remaining_amount = new_deposited.checked_sub(streamed_amount)?;
+            elapsed
+                .checked_add(remaining_amount - 1)?
+                .checked_div(remaining_amount)
+        })
+        .and_then(|elapsed| i64::try_from(elapsed).ok())
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+
+    Ok(now
+        .checked_sub(elapsed_time)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?
+        .max(start_time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Amounts, BaseStream};
+    use proptest::prelude::*;
+
+    fn streamed(deposited: u64, start_time: i64, cliff_time: i64, end_time: i64, now: i64) -> u64 {
+        calculate_lockup_linear_streamed_amount(deposited, start_time, cliff_time, end_time, now)
+            .unwrap()
+    }
+
+    fn lockup_linear_stream(deposited: u64, start_time: i64, end_time: i64) -> LockupLinearStream {
+        LockupLinearStream {
+            base_stream: BaseStream {
+                index: 0,
+                bump: 0,
+                treasury_bump: 0,
+                name: "Test".to_string(),
+                sender: Pubkey::default(),
+                recipient: Pubkey::default(),
+                token_mint: Pubkey::default(),
+                amounts: Amounts {
+                    deposited,
+                    protocol_fee: 0,
+                    refunded: 0,
+                    withdrawn: 0,
+                },
+                start_time,
+                end_time,
+                is_cancelable: true,
+                is_canceled: false,
+                is_transferable: true,
+            },
+            cliff_time: start_time,
+            nft_mint: Pubkey::default(),
+            paused_time: None,
+            paused_duration: 0,
+            operator_approval: None,
+            start_unlock: 0,
+            cliff_unlock: 0,
+            has_legacy_address: false,
+            rent_payer: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn streams_nothing_before_the_cliff() {
+        assert_eq!(streamed(1_000, 100, 150, 200, 99), 0);
+        assert_eq!(streamed(1_000, 100, 150, 200, 149), 0);
+        assert_eq!(streamed(1_000, 100, 150, 200, 150), 500);
+    }
+
+    #[test]
+    fn streams_everything_at_the_end() {
+        assert_eq!(streamed(1_000, 100, 100, 200, 200), 1_000);
+        assert_eq!(streamed(u64::MAX, 100, 100, 200, i64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn keeps_precision_above_f64_mantissa() {
+        let deposited = (1u64 << 53) + 1;
+        assert_eq!(streamed(deposited, 0, 0, 2, 1), deposited / 2);
+        assert_eq!(streamed(u64::MAX, 0, 0, 3, 1), u64::MAX / 3);
+    }
+
+    #[test]
+    fn withdrawn_and_refunded_never_exceed_deposited_at_every_time_point() {
+        let deposited = (1u64 << 60) + 12_345;
+        let (start_time, cliff_time, end_time) = (1_000, 1_250, 2_000);
+
+        for cancel_time in start_time - 10..=end_time + 10 {
+            let refunded =
+                deposited - streamed(deposited, start_time, cliff_time, end_time, cancel_time);
+
+            for withdraw_time in start_time - 10..=cancel_time {
+                let withdrawn =
+                    streamed(deposited, start_time, cliff_time, end_time, withdraw_time);
+                assert!(withdrawn + refunded <= deposited);
+
+                // Withdrawing everything right before canceling leaves no dust behind
+                if withdraw_time == cancel_time {
+                    assert_eq!(withdrawn + refunded, deposited);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn freezes_the_streamed_amount_once_canceled() {
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+
+        // Cancel at 25% of the stream's duration
+        let streamed_at_cancel = get_lockup_linear_streamed_amount(&stream, 125).unwrap();
+        stream.base_stream.amounts.refunded = 1_000 - streamed_at_cancel;
+        stream.base_stream.is_canceled = true;
+
+        assert_eq!(streamed_at_cancel, 250);
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 150).unwrap(),
+            250
+        );
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 300).unwrap(),
+            250
+        );
+    }
+
+    #[test]
+    fn excludes_the_paused_duration_from_the_elapsed_time() {
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+        stream.cliff_time = 150;
+        stream.paused_time = Some(160);
+
+        // The streamed amount is frozen while paused
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 190).unwrap(),
+            600
+        );
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 500).unwrap(),
+            600
+        );
+
+        // Resuming after 40 seconds pushes the whole schedule back by as much
+        assert_eq!(unpause_lockup_linear_stream(&mut stream, 200).unwrap(), 40);
+        assert_eq!(stream.paused_time, None);
+        assert_eq!(stream.paused_duration, 40);
+        assert_eq!(stream.base_stream.start_time, 140);
+        assert_eq!(stream.cliff_time, 190);
+        assert_eq!(stream.base_stream.end_time, 240);
+
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 200).unwrap(),
+            600
+        );
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 240).unwrap(),
+            1_000
+        );
+
+        // Resuming a stream that is not paused leaves it untouched
+        assert_eq!(unpause_lockup_linear_stream(&mut stream, 300).unwrap(), 0);
+        assert_eq!(stream.base_stream.end_time, 240);
+    }
+
+    #[test]
+    fn refunds_the_unstreamed_amount_while_cancelable() {
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+
+        assert_eq!(
+            get_lockup_linear_refundable_amount(&stream, 50).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            get_lockup_linear_refundable_amount(&stream, 125).unwrap(),
+            750
+        );
+        assert_eq!(
+            get_lockup_linear_refundable_amount(&stream, 200).unwrap(),
+            0
+        );
+
+        stream.base_stream.is_cancelable = false;
+        assert_eq!(
+            get_lockup_linear_refundable_amount(&stream, 125).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn refunds_a_paused_stream_as_if_resumed_past_its_end_time() {
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+        stream.paused_time = Some(160);
+
+        // Resuming at 300 pushes the end time back to 340, with 600 tokens streamed so far
+        assert_eq!(
+            get_lockup_linear_refundable_amount(&stream, 300).unwrap(),
+            400
+        );
+
+        // The refundable amount matches what cancel refunds after resuming the stream
+        unpause_lockup_linear_stream(&mut stream, 300).unwrap();
+        assert_eq!(
+            1_000 - get_lockup_linear_streamed_amount(&stream, 300).unwrap(),
+            400
+        );
+    }
+
+    #[test]
+    fn unlocks_the_start_and_cliff_amounts_ahead_of_the_linear_schedule() {
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+        stream.cliff_time = 150;
+        stream.start_unlock = 100;
+        stream.cliff_unlock = 200;
+
+        assert_eq!(get_lockup_linear_streamed_amount(&stream, 99).unwrap(), 0);
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 100).unwrap(),
+            100
+        );
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 149).unwrap(),
+            100
+        );
+        // The remaining 700 tokens are streamed linearly from the start time
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 150).unwrap(),
+            650
+        );
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 175).unwrap(),
+            825
+        );
+        assert_eq!(
+            get_lockup_linear_streamed_amount(&stream, 200).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            get_lockup_linear_refundable_amount(&stream, 149).unwrap(),
+            900
+        );
+    }
+
+    #[test]
+    fn reports_the_status_over_the_lifecycle() {
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+
+        assert_eq!(get_lockup_linear_status(&stream, 99), StreamStatus::Pending);
+        assert_eq!(
+            get_lockup_linear_status(&stream, 100),
+            StreamStatus::Streaming
+        );
+        assert_eq!(
+            get_lockup_linear_status(&stream, 200),
+            StreamStatus::Settled
+        );
+
+        stream.paused_time = Some(150);
+        assert_eq!(get_lockup_linear_status(&stream, 200), StreamStatus::Paused);
+        stream.paused_time = None;
+
+        stream.base_stream.is_canceled = true;
+        stream.base_stream.amounts.refunded = 500;
+        assert_eq!(
+            get_lockup_linear_status(&stream, 200),
+            StreamStatus::Canceled
+        );
+
+        stream.base_stream.amounts.withdrawn = 500;
+        assert_eq!(
+            get_lockup_linear_status(&stream, 200),
+            StreamStatus::Depleted
+        );
+    }
+
+    #[test]
+    fn extends_the_end_time_at_the_same_rate() {
+        assert_eq!(
+            calculate_extended_end_time(1_000, 100, 200, 500).unwrap(),
+            250
+        );
+        assert_eq!(
+            calculate_extended_end_time(1_000, 100, 200, 1).unwrap(),
+            200
+        );
+
+        // The amount streamed so far does not change
+        assert_eq!(
+            streamed(1_500, 100, 100, 250, 150),
+            streamed(1_000, 100, 100, 200, 150)
+        );
+    }
+
+    #[test]
+    fn rejects_extending_a_stream_without_a_linear_schedule() {
+        assert_eq!(
+            calculate_extended_end_time(0, 100, 200, 500).unwrap_err(),
+            error!(Error::Validation::Stream::NoStreamableAmount)
+        );
+
+        // A deposit made only of unlocks leaves nothing to stream linearly
+        let mut stream = lockup_linear_stream(1_000, 100, 200);
+        stream.cliff_time = 150;
+        stream.start_unlock = 400;
+        stream.cliff_unlock = 600;
+        assert_eq!(get_lockup_linear_streamable_amount(&stream).unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_overflowing_unlocks() {
+        assert_eq!(
+            calculate_lockup_linear_unlocked_amount(400, 600).unwrap(),
+            1_000
+        );
+        assert!(calculate_lockup_linear_unlocked_amount(u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn rebases_the_start_time_over_the_remaining_period() {
+        // Half of 1K is streamed at 150, the other half plus 500 is spread over the last 50 seconds
+        let start_time = calculate_rebased_start_time(1_000, 100, 200, 500, 150).unwrap();
+        assert_eq!(start_time, 125);
+        assert_eq!(streamed(1_500, start_time, start_time, 200, 150), 500);
+        assert_eq!(streamed(1_500, start_time, start_time, 200, 175), 1_000);
+
+        // Nothing moves before the stream starts
+        assert_eq!(
+            calculate_rebased_start_time(1_000, 100, 200, 500, 50).unwrap(),
+            100
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn streamed_amount_is_monotonic_and_bounded(
+            deposited in any::<u64>(),
+            start_time in 0i64..1_000_000,
+            cliff_offset in 0i64..1_000_000,
+            duration in 1i64..10_000_000,
+            first in 0i64..12_000_000,
+            second in 0i64..12_000_000,
+        ) {
+            let end_time = start_time + duration;
+            let cliff_time = (start_time + cliff_offset).min(end_time);
+            let (earlier, later) = (first.min(second), first.max(second));
+
+            let streamed_earlier = streamed(deposited, start_time, cliff_time, end_time, earlier);
+            let streamed_later = streamed(deposited, start_time, cliff_time, end_time, later);
+
+            prop_assert!(streamed_earlier <= streamed_later);
+            prop_assert!(streamed_later <= deposited);
+        }
+
+        #[test]
+        fn withdrawn_and_refunded_never_exceed_deposited(
+            deposited in any::<u64>(),
+            start_time in 0i64..1_000_000,
+            cliff_offset in 0i64..1_000_000,
+            duration in 1i64..10_000_000,
+            withdraw_time in 0i64..12_000_000,
+            cancel_delay in 0i64..12_000_000,
+        ) {
+            let end_time = start_time + duration;
+            let cliff_time = (start_time + cliff_offset).min(end_time);
+            let cancel_time = withdraw_time + cancel_delay;
+
+            // The recipient withdraws everything available, then the sender cancels
+            let withdrawn = streamed(deposited, start_time, cliff_time, end_time, withdraw_time);
+            let refunded = deposited
+                - streamed(deposited, start_time, cliff_time, end_time, cancel_time);
+
+            prop_assert!(withdrawn as u128 + refunded as u128 <= deposited as u128);
+        }
+
+        #[test]
+        fn top_ups_never_decrease_the_streamed_amount(
+            deposited in 1u64..u64::MAX / 2,
+            amount in 1u64..u64::MAX / 2,
+            start_time in 0i64..1_000_000,
+            duration in 1i64..10_000_000,
+            now_offset in 0i64..10_000_000,
+            later_offset in 0i64..10_000_000,
+        ) {
+            let end_time = start_time + duration;
+            let now = start_time + now_offset % duration;
+            let later = now + later_offset;
+            let new_deposited = deposited + amount;
+
+            let extended_end_time = calculate_extended_end_time(deposited, start_time, end_time, amount).unwrap();
+            prop_assert!(
+                streamed(new_deposited, start_time, start_time, extended_end_time, later)
+                    >= streamed(deposited, start_time, start_time, end_time, later)
+            );
+
+            let rebased_start_time = calculate_rebased_start_time(deposited, start_time, end_time, amount, now).unwrap();
+            prop_assert!(
+                streamed(new_deposited, rebased_start_time, rebased_start_time, end_time, later)
+                    >= streamed(deposited, start_time, start_time, end_time, later)
+            );
+        }
+    }
+}
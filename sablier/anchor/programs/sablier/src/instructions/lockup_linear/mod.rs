@@ -1,14 +1,47 @@
+pub use approve_operator::*;
+pub mod approve_operator;
+
 pub use cancel::*;
 pub mod cancel;
 
+pub use close::*;
+pub mod close;
+
 pub use create::*;
 pub mod create;
 
-pub use initialize::*;
-pub mod initialize;
+pub use create_batch::*;
+pub mod create_batch;
+
+pub use math::*;
+pub mod math;
+
+pub use pause::*;
+pub mod pause;
 
 pub use renounce::*;
 pub mod renounce;
 
+pub use resume::*;
+pub mod resume;
+
+pub use revoke_operator::*;
+pub mod revoke_operator;
+
+pub use top_up::*;
+pub mod top_up;
+
+pub use transfer::*;
+pub mod transfer;
+
+pub use views::*;
+pub mod views;
+
 pub use withdraw::*;
 pub mod withdraw;
+
+pub use withdraw_max::*;
+pub mod withdraw_max;
+
+pub use withdraw_to::*;
+pub mod withdraw_to;
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events, get_lockup_linear_status, seeds::LOCKUP_LINEAR_STREAM, validate_pause,
+    LockupLinearStream,
+};
+
+/// Pauses a lockup linear stream, freezing its streamed amount without refunding anything.
+///
+/// The recipient can still withdraw what was streamed before the pause.
+pub fn process_pause_lockup_linear_stream(ctx: Context<PauseLockupLinearStream>) -> Result<()> {
+    msg!("Validating Pause Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    let now = Clock::get()?.unix_timestamp;
+    validate_pause(
+        ctx.accounts.sender.key(),
+        &stream.base_stream,
+        get_lockup_linear_status(stream, now),
+    )?;
+    msg!("Validation successful ✅ Pausing stream... ⏳");
+
+    stream.paused_time = Some(now);
+    msg!("Stream paused ⏸️");
+
+    emit!(events::PauseLockupStream {
+        stream: stream.key(),
+        sender: stream.base_stream.sender,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `pause_lockup_linear_stream`
+#[derive(Accounts)]
+pub struct PauseLockupLinearStream<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+}
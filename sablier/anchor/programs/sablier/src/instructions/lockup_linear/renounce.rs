@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    extract_stream_counter_index, seeds::LOCKUP_LINEAR_STREAM, validate_renounce,
+    events, get_lockup_linear_status, seeds::LOCKUP_LINEAR_STREAM, validate_renounce,
     LockupLinearStream,
 };
 
@@ -10,13 +10,26 @@ pub fn process_renounce_cancelability_lockup_linear_stream(
     ctx: Context<RenounceCancelabilityLockupLinearStream>,
 ) -> Result<()> {
     msg!("Validating Renounce Operation... 🛂");
-    validate_renounce(ctx.accounts.sender.key(), &ctx.accounts.stream.base_stream)?;
+    // Paused streams cannot be renounced, or they could never be resumed once their sender loses interest
+    let status = get_lockup_linear_status(&ctx.accounts.stream, Clock::get()?.unix_timestamp);
+    validate_renounce(
+        ctx.accounts.sender.key(),
+        &ctx.accounts.stream.base_stream,
+        status,
+    )?;
     msg!("Validation successful! ✅ Renouncing cancelability... ⏳");
 
+    let stream_key = ctx.accounts.stream.key();
     let base_stream = &mut ctx.accounts.stream.base_stream;
 
     // Mark the stream as no longer cancelable
     base_stream.is_cancelable = false;
+
+    emit!(events::RenounceLockupStream {
+        stream: stream_key,
+        sender: base_stream.sender,
+    });
+
     Ok(())
 }
 
@@ -30,9 +43,10 @@ pub struct RenounceCancelabilityLockupLinearStream<'info> {
         mut,
         seeds = [
             LOCKUP_LINEAR_STREAM.as_ref(),
-            &extract_stream_counter_index(&stream.base_stream.id),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
         ],
-        bump
+        bump = stream.base_stream.bump
     )]
     pub stream: Account<'info, LockupLinearStream>,
 }
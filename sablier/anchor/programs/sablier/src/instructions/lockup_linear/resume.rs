@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events, get_lockup_linear_status, seeds::LOCKUP_LINEAR_STREAM, unpause_lockup_linear_stream,
+    validate_resume, LockupLinearStream,
+};
+
+/// Resumes a paused lockup linear stream, pushing its schedule back by the time spent paused.
+pub fn process_resume_lockup_linear_stream(ctx: Context<ResumeLockupLinearStream>) -> Result<()> {
+    msg!("Validating Resume Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    let now = Clock::get()?.unix_timestamp;
+    validate_resume(
+        ctx.accounts.sender.key(),
+        &stream.base_stream,
+        get_lockup_linear_status(stream, now),
+    )?;
+    msg!("Validation successful ✅ Resuming stream... ⏳");
+
+    let pause_duration = unpause_lockup_linear_stream(stream, now)?;
+    msg!(
+        "Stream resumed after {} seconds, now ending at {} ▶️",
+        pause_duration,
+        stream.base_stream.end_time
+    );
+
+    emit!(events::ResumeLockupStream {
+        stream: stream.key(),
+        sender: stream.base_stream.sender,
+        pause_duration,
+        end_time: stream.base_stream.end_time,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `resume_lockup_linear_stream`
+#[derive(Accounts)]
+pub struct ResumeLockupLinearStream<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+}
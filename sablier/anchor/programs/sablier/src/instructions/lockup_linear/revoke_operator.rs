@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{error::Error, events, seeds::LOCKUP_LINEAR_STREAM, LockupLinearStream};
+
+/// Revokes the operator approved to withdraw from a lockup linear stream.
+pub fn process_revoke_lockup_linear_stream_operator(
+    ctx: Context<RevokeLockupLinearStreamOperator>,
+) -> Result<()> {
+    msg!("Validating Revoke Operation... 🛂");
+    let stream = &mut ctx.accounts.stream;
+    let approval = stream
+        .operator_approval
+        .take()
+        .ok_or(Error::Validation::Stream::MissingOperator)?;
+    msg!("Validation successful ✅ Revoking operator... ⏳");
+    msg!("Operator {} revoked 🚫", approval.operator);
+
+    emit!(events::RevokeLockupStreamOperator {
+        stream: stream.key(),
+        recipient: ctx.accounts.recipient.key(),
+        operator: approval.operator,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `revoke_lockup_linear_stream_operator`
+#[derive(Accounts)]
+pub struct RevokeLockupLinearStreamOperator<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+
+    /// The recipient's token account holding the stream NFT, which grants the right to revoke operators
+    #[account(
+        constraint = recipient_nft_token_account.mint == stream.nft_mint
+            && recipient_nft_token_account.owner == recipient.key()
+            && recipient_nft_token_account.amount == 1
+            @ Error::Authorization::Stream::UnauthorizedOperatorApproval,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
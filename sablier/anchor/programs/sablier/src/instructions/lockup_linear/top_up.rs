@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    calculate_extended_end_time, calculate_net_transfer_amount, calculate_protocol_fee,
+    calculate_rebased_start_time,
+    error::Error,
+    events, get_lockup_linear_status, get_lockup_linear_streamable_amount,
+    seeds::{CONFIG, LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY, PROTOCOL_FEE_VAULT},
+    validate_top_up, Config, LockupLinearStream,
+};
+
+/// How the tokens added by a top-up are streamed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TopUpMode {
+    /// Keeps the current rate and pushes the end time back.
+    ExtendEndTime,
+    /// Keeps the end time and streams the added tokens over the remaining period.
+    IncreaseRate,
+}
+
+/// Adds tokens to an active lockup linear stream, either extending it or increasing its rate.
+///
+/// The protocol fee is charged on the added amount, as on creation, and the amount streamed so
+/// far is never decreased by a top-up. With a Token-2022 transfer fee, only what the treasury
+/// actually receives is added to the deposit.
+pub fn process_top_up_lockup_linear_stream(
+    ctx: Context<TopUpLockupLinearStream>,
+    amount: u64,
+    mode: TopUpMode,
+) -> Result<()> {
+    msg!("Validating Top Up Operation... 🛂");
+    let now = Clock::get()?.unix_timestamp;
+    validate_top_up(
+        ctx.accounts.sender.key(),
+        &ctx.accounts.stream.base_stream,
+        get_lockup_linear_status(&ctx.accounts.stream, now),
+        amount,
+    )?;
+    msg!("Validation successful ✅ Topping up stream... ⏳");
+
+    // Split the amount between the stream's deposit and the protocol fee
+    let protocol_fee = calculate_protocol_fee(amount, ctx.accounts.config.protocol_fee_bps)?;
+    let deposited_amount = amount
+        .checked_sub(protocol_fee)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    let received_amount = calculate_net_transfer_amount(
+        &ctx.accounts.token_mint.to_account_info(),
+        deposited_amount,
+    )?;
+
+    // The unlocks are fixed amounts, so only the linearly streamed part shapes the new schedule
+    let streamable_amount = get_lockup_linear_streamable_amount(&ctx.accounts.stream)?;
+    let stream_key = ctx.accounts.stream.key();
+    let LockupLinearStream {
+        base_stream,
+        cliff_time,
+        ..
+    } = &mut **ctx.accounts.stream;
+    match mode {
+        TopUpMode::ExtendEndTime => {
+            base_stream.end_time = calculate_extended_end_time(
+                streamable_amount,
+                base_stream.start_time,
+                base_stream.end_time,
+                received_amount,
+            )?;
+        }
+        TopUpMode::IncreaseRate => {
+            base_stream.start_time = calculate_rebased_start_time(
+                streamable_amount,
+                base_stream.start_time,
+                base_stream.end_time,
+                received_amount,
+                now,
+            )?;
+            *cliff_time = (*cliff_time).max(base_stream.start_time);
+        }
+    }
+
+    base_stream.amounts.deposited = base_stream
+        .amounts
+        .deposited
+        .checked_add(received_amount)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    base_stream.amounts.protocol_fee = base_stream
+        .amounts
+        .protocol_fee
+        .checked_add(protocol_fee)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream topped up with {} tokens, now ending at {} 📈",
+        received_amount,
+        base_stream.end_time
+    );
+
+    emit!(events::TopUpLockupStream {
+        stream: stream_key,
+        sender: base_stream.sender,
+        deposited_amount: received_amount,
+        protocol_fee,
+        start_time: base_stream.start_time,
+        end_time: base_stream.end_time,
+    });
+
+    // Transfer the added tokens into the treasury
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.sender_token_account.to_account_info(),
+        to: ctx.accounts.treasury_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        authority: ctx.accounts.sender.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    transfer_checked(cpi_ctx, deposited_amount, ctx.accounts.token_mint.decimals)?;
+    msg!("Transferred {} tokens to the treasury 💸", deposited_amount);
+
+    // Transfer the protocol fee into the fee vault
+    if protocol_fee > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.sender_token_account.to_account_info(),
+            to: ctx.accounts.fee_vault_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            authority: ctx.accounts.sender.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, protocol_fee, ctx.accounts.token_mint.decimals)?;
+        msg!("Transferred {} tokens to the fee vault 🧾", protocol_fee);
+    }
+
+    Ok(())
+}
+
+/// Accounts for `top_up_lockup_linear_stream`.
+#[derive(Accounts)]
+pub struct TopUpLockupLinearStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Box<Account<'info, LockupLinearStream>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.base_stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [CONFIG.as_ref()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        token::mint = token_mint,
+        token::authority = fee_vault_token_account,
+        token::token_program = token_program,
+        seeds = [PROTOCOL_FEE_VAULT.as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
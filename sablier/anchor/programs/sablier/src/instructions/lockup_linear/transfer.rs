@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked},
+};
+
+use crate::{
+    error::Error, events, get_lockup_linear_status, seeds::LOCKUP_LINEAR_STREAM, validate_transfer,
+    LockupLinearStream,
+};
+
+/// Transfers a lockup linear stream, along with its NFT, to a new recipient.
+pub fn process_transfer_lockup_linear_stream(
+    ctx: Context<TransferLockupLinearStream>,
+) -> Result<()> {
+    msg!("Validating Transfer Operation... 🛂");
+    let status = get_lockup_linear_status(&ctx.accounts.stream, Clock::get()?.unix_timestamp);
+    validate_transfer(&ctx.accounts.stream.base_stream, status)?;
+    msg!("Validation successful ✅ Transferring stream... ⏳");
+
+    // Hand the stream NFT over to the new recipient
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.recipient_nft_token_account.to_account_info(),
+        to: ctx
+            .accounts
+            .new_recipient_nft_token_account
+            .to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        authority: ctx.accounts.recipient.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.nft_token_program.to_account_info(),
+        cpi_accounts,
+    );
+    transfer_checked(cpi_ctx, 1, ctx.accounts.nft_mint.decimals)?;
+
+    let new_recipient = ctx.accounts.new_recipient.key();
+    let base_stream = &mut ctx.accounts.stream.base_stream;
+    let old_recipient = base_stream.recipient;
+    base_stream.recipient = new_recipient;
+
+    // The new recipient has not approved anyone yet
+    ctx.accounts.stream.operator_approval = None;
+    msg!("Stream transferred to {} 🤝", new_recipient);
+
+    emit!(events::TransferLockupStream {
+        stream: ctx.accounts.stream.key(),
+        old_recipient,
+        new_recipient,
+    });
+
+    Ok(())
+}
+
+/// Accounts for `transfer_lockup_linear_stream`.
+#[derive(Accounts)]
+pub struct TransferLockupLinearStream<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: The new recipient only receives the stream NFT, any account is allowed.
+    pub new_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+
+    #[account(address = stream.nft_mint)]
+    pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient_nft_token_account.mint == stream.nft_mint
+            && recipient_nft_token_account.owner == recipient.key()
+            && recipient_nft_token_account.amount == 1
+            @ Error::Authorization::Stream::UnauthorizedTransfer,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = nft_mint,
+        associated_token::authority = new_recipient,
+        associated_token::token_program = nft_token_program,
+    )]
+    pub new_recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub nft_token_program: Program<'info, Token2022>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    get_lockup_linear_refundable_amount, get_lockup_linear_status,
+    get_lockup_linear_streamed_amount, get_lockup_linear_withdrawable_amount,
+    seeds::LOCKUP_LINEAR_STREAM, LockupLinearStream, StreamStatus,
+};
+
+/// Returns the amount streamed so far by a lockup linear stream.
+pub fn process_streamed_amount_of(ctx: Context<LockupLinearStreamView>) -> Result<u64> {
+    get_lockup_linear_streamed_amount(&ctx.accounts.stream, Clock::get()?.unix_timestamp)
+}
+
+/// Returns the amount that the recipient of a lockup linear stream can currently withdraw.
+pub fn process_withdrawable_amount_of(ctx: Context<LockupLinearStreamView>) -> Result<u64> {
+    get_lockup_linear_withdrawable_amount(&ctx.accounts.stream, Clock::get()?.unix_timestamp)
+}
+
+/// Returns the amount that would be refunded to the sender if the lockup linear stream was canceled now.
+pub fn process_refundable_amount_of(ctx: Context<LockupLinearStreamView>) -> Result<u64> {
+    get_lockup_linear_refundable_amount(&ctx.accounts.stream, Clock::get()?.unix_timestamp)
+}
+
+/// Returns the current status of a lockup linear stream.
+pub fn process_status_of(ctx: Context<LockupLinearStreamView>) -> Result<StreamStatus> {
+    Ok(get_lockup_linear_status(
+        &ctx.accounts.stream,
+        Clock::get()?.unix_timestamp,
+    ))
+}
+
+/// Accounts for the read-only lockup linear stream views, whose results are set as return data
+#[derive(Accounts)]
+pub struct LockupLinearStreamView<'info> {
+    #[account(
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+}
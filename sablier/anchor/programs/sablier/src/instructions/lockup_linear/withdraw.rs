@@ -1,120 +1,159 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
+    call_stream_hook,
     error::Error,
-    extract_stream_counter_index,
-    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY},
-    validate_withdraw, LockupLinearStream,
+    get_lockup_linear_status, get_lockup_linear_streamed_amount,
+    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY, STREAM_HOOK},
+    validate_withdraw, withdraw_from_lockup_stream, LockupLinearStream, LockupTreasuryTransfer,
+    WithdrawHookArgs, WITHDRAW_HOOK,
 };
 
-pub fn process_withdraw_from_lockup_linear_stream(
-    ctx: Context<WithdrawFromLockupLinearStream>,
+pub fn process_withdraw_from_lockup_linear_stream<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFromLockupLinearStream<'info>>,
     amount: u64,
 ) -> Result<()> {
-    msg!("Validating Withdraw Operation... 🛂");
-    validate_withdraw(
+    withdraw_from_lockup_linear_treasury(
+        &mut ctx.accounts.stream,
         ctx.accounts.recipient.key(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
         amount,
-        &ctx.accounts.stream.base_stream,
     )?;
 
-    let stream = &mut ctx.accounts.stream;
-    let now = Clock::get()?.unix_timestamp as i64;
-
-    require!(
-        stream.cliff_time <= now,
-        Error::Validation::Stream::CliffNotEnded
-    );
-
-    let elapsed_amount = get_elapsed_amount(stream, now);
-    let available_amount = elapsed_amount - stream.base_stream.amounts.withdrawn;
-    msg!(
-        "Elapsed Amount: {} | Available Amount: {} 🏧",
-        elapsed_amount,
-        available_amount
-    );
-    require!(
-        available_amount >= amount,
-        Error::Validation::Stream::ExceedsBalance
-    );
-    msg!("Validation successful ✅ Withdrawing from stream... ⏳");
+    call_lockup_linear_withdraw_hook(
+        &ctx.accounts.recipient_hook,
+        &ctx.accounts.stream,
+        ctx.accounts.recipient.key(),
+        ctx.remaining_accounts,
+        amount,
+    )
+}
 
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.treasury_token_account.to_account_info(),
-        to: ctx.accounts.recipient_token_account.to_account_info(),
-        mint: ctx.accounts.token_mint.to_account_info(),
-        authority: ctx.accounts.treasury_token_account.to_account_info(),
+/// Notifies the recipient's hook program of a withdrawal, if one is registered for the recipient.
+pub(crate) fn call_lockup_linear_withdraw_hook<'info>(
+    recipient_hook: &AccountInfo<'info>,
+    stream: &Account<'info, LockupLinearStream>,
+    recipient: Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let args = WithdrawHookArgs {
+        stream: stream.key(),
+        stream_index: stream.base_stream.index,
+        sender: stream.base_stream.sender,
+        recipient,
+        amount,
     };
 
-    let mint_key = ctx.accounts.token_mint.key();
-    let stream_counter_index = extract_stream_counter_index(&stream.base_stream.id);
-
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        LOCKUP_LINEAR_TREASURY.as_ref(),
-        mint_key.as_ref(),
-        &stream_counter_index,
-        &[ctx.bumps.treasury_token_account],
-    ]];
-
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
-        .with_signer(signer_seeds);
-    transfer_checked(cpi_ctx, amount, ctx.accounts.token_mint.decimals)?;
-    msg!("Transfer successful 💸");
-
-    stream.base_stream.amounts.withdrawn += amount;
-
-    Ok(())
+    call_stream_hook(
+        recipient_hook,
+        stream,
+        remaining_accounts,
+        WITHDRAW_HOOK,
+        &args,
+    )
 }
 
-fn get_elapsed_amount(stream: &LockupLinearStream, now: i64) -> u64 {
-    if now >= stream.base_stream.end_time {
-        return stream.base_stream.amounts.deposited;
-    }
+/// Validates the withdrawal of the given amount and transfers it from the stream's treasury to the destination.
+///
+/// `recipient` is the current holder of the stream NFT, which the stored recipient may lag behind.
+pub(crate) fn withdraw_from_lockup_linear_treasury<'info>(
+    stream: &mut Account<'info, LockupLinearStream>,
+    recipient: Pubkey,
+    treasury_token_account: &InterfaceAccount<'info, TokenAccount>,
+    destination_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    msg!("Validating Withdraw Operation... 🛂");
+    let now = Clock::get()?.unix_timestamp;
+    validate_withdraw(amount, get_lockup_linear_status(stream, now))?;
+
+    // A start unlock can be withdrawn before the cliff
+    require!(
+        stream.cliff_time <= now || stream.start_unlock > 0,
+        Error::Validation::Stream::CliffNotEnded
+    );
 
-    let elapsed_time = now - stream.base_stream.start_time;
-    let total_time = stream.base_stream.end_time - stream.base_stream.start_time;
-    let total_amount = stream.base_stream.amounts.deposited;
+    let streamed_amount = get_lockup_linear_streamed_amount(stream, now)?;
 
-    let elapsed_percentage = elapsed_time as f64 / total_time as f64;
+    let stream_key = stream.key();
+    let treasury_bump = [stream.base_stream.treasury_bump];
 
-    (elapsed_percentage * total_amount as f64) as u64
+    withdraw_from_lockup_stream(
+        stream_key,
+        &mut stream.base_stream,
+        recipient,
+        streamed_amount,
+        amount,
+        LockupTreasuryTransfer {
+            treasury_token_account,
+            destination_token_account,
+            token_mint,
+            token_program,
+            treasury_seeds: &[
+                LOCKUP_LINEAR_TREASURY.as_ref(),
+                stream_key.as_ref(),
+                &treasury_bump,
+            ],
+        },
+    )
 }
 
 /// Accounts for `withdraw_from_lockup_linear_stream`
+///
+/// Anyone can trigger the withdrawal, but the funds always go to the recipient's associated token account.
 #[derive(Accounts)]
 pub struct WithdrawFromLockupLinearStream<'info> {
     #[account(mut)]
-    pub recipient: Signer<'info>,
+    pub signer: Signer<'info>,
+
+    /// CHECK: Verified as the holder of the stream NFT through `recipient_nft_token_account`
+    pub recipient: UncheckedAccount<'info>,
 
     #[account(
         mut,
         seeds = [
             LOCKUP_LINEAR_STREAM.as_ref(),
-            &extract_stream_counter_index(&stream.base_stream.id)
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
         ],
-        constraint = stream.base_stream.recipient == recipient.key() @ Error::Authorization::Stream::UnauthorizedWithdraw,
-        bump
+        bump = stream.base_stream.bump
     )]
     pub stream: Account<'info, LockupLinearStream>,
 
+    /// The recipient's token account holding the stream NFT, which identifies the current recipient
+    #[account(
+        constraint = recipient_nft_token_account.mint == stream.nft_mint
+            && recipient_nft_token_account.owner == recipient.key()
+            && recipient_nft_token_account.amount == 1
+            @ Error::Authorization::Stream::UnauthorizedWithdraw,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: The recipient's Stream Hook, which is only called back when it was registered
+    #[account(seeds = [STREAM_HOOK.as_ref(), recipient.key().as_ref()], bump)]
+    pub recipient_hook: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        seeds = [
-            LOCKUP_LINEAR_TREASURY.as_ref(),
-            token_mint.key().as_ref(),
-            &extract_stream_counter_index(&stream.base_stream.id),
-        ],
-        bump
+        token::mint = token_mint,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.base_stream.treasury_bump
     )]
     pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init_if_needed,
-        payer = recipient,
+        payer = signer,
         associated_token::mint = token_mint,
         associated_token::authority = recipient,
         associated_token::token_program = token_program,
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    call_lockup_linear_withdraw_hook, error::Error, get_lockup_linear_withdrawable_amount,
+    withdraw_from_lockup_linear_treasury, WithdrawFromLockupLinearStream,
+};
+
+/// Withdraws the full amount that is currently withdrawable from a lockup linear stream.
+pub fn process_withdraw_max_from_lockup_linear_stream<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFromLockupLinearStream<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let withdrawable_amount = get_lockup_linear_withdrawable_amount(&ctx.accounts.stream, now)?;
+    msg!("Withdrawable Amount: {} 🏧", withdrawable_amount);
+    require!(
+        withdrawable_amount > 0,
+        Error::Validation::Stream::NothingToWithdraw
+    );
+
+    withdraw_from_lockup_linear_treasury(
+        &mut ctx.accounts.stream,
+        ctx.accounts.recipient.key(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        withdrawable_amount,
+    )?;
+
+    call_lockup_linear_withdraw_hook(
+        &ctx.accounts.recipient_hook,
+        &ctx.accounts.stream,
+        ctx.accounts.recipient.key(),
+        ctx.remaining_accounts,
+        withdrawable_amount,
+    )
+}
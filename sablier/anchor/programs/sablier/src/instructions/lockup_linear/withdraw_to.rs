@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    call_lockup_linear_withdraw_hook,
+    error::Error,
+    seeds::{LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY, STREAM_HOOK},
+    withdraw_from_lockup_linear_treasury, LockupLinearStream,
+};
+
+/// Withdraws the given amount from a lockup linear stream to a destination token account chosen by
+/// the recipient or by the operator they approved.
+///
+/// The recipient's hook program is called as for any other withdrawal.
+pub fn process_withdraw_from_lockup_linear_stream_to<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFromLockupLinearStreamTo<'info>>,
+    amount: u64,
+) -> Result<()> {
+    withdraw_from_lockup_linear_treasury(
+        &mut ctx.accounts.stream,
+        ctx.accounts.recipient.key(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    call_lockup_linear_withdraw_hook(
+        &ctx.accounts.recipient_hook,
+        &ctx.accounts.stream,
+        ctx.accounts.recipient.key(),
+        ctx.remaining_accounts,
+        amount,
+    )
+}
+
+/// Accounts for `withdraw_from_lockup_linear_stream_to`
+#[derive(Accounts)]
+pub struct WithdrawFromLockupLinearStreamTo<'info> {
+    #[account(
+        constraint = signer.key() == recipient.key()
+            || stream.is_approved_operator(signer.key(), recipient.key())
+            @ Error::Authorization::Stream::UnauthorizedWithdraw,
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Verified as the holder of the stream NFT through `recipient_nft_token_account`
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_LINEAR_STREAM.as_ref(),
+            stream.sender_seed(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupLinearStream>,
+
+    /// The recipient's token account holding the stream NFT, which identifies the current recipient
+    #[account(
+        constraint = recipient_nft_token_account.mint == stream.nft_mint
+            && recipient_nft_token_account.owner == recipient.key()
+            && recipient_nft_token_account.amount == 1
+            @ Error::Authorization::Stream::UnauthorizedWithdraw,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: The recipient's Stream Hook, which is only called back when it was registered
+    #[account(seeds = [STREAM_HOOK.as_ref(), recipient.key().as_ref()], bump)]
+    pub recipient_hook: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump = stream.base_stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,179 @@
+use crate::{
+    error::Error,
+    events, fund_lockup_stream,
+    seeds::{LOCKUP_TRANCHED_STREAM, LOCKUP_TRANCHED_STREAM_COUNTER, LOCKUP_TRANCHED_TREASURY},
+    validate_create_team_lockup_stream, Amounts, BaseStream, LockupTranchedStream, StreamCounter,
+    Tranche, ANCHOR_DISCRIMINATOR, MAX_TRANCHE_COUNT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Creates a new lockup tranched stream, transferring funds to a treasury account.
+pub fn process_create_lockup_tranched_stream(
+    ctx: Context<CreateLockupTranchedStream>,
+    name: String,
+    recipient: Pubkey,
+    amount: u64,
+    start_time: i64,
+    tranches: Vec<Tranche>,
+    is_cancelable: bool,
+    is_transferable: bool,
+) -> Result<()> {
+    msg!("Validating Create Operation... 🛂");
+    validate_tranches(start_time, amount, &tranches)?;
+
+    // The stream ends when the last tranche unlocks
+    let end_time = tranches.last().unwrap().timestamp;
+    validate_create_team_lockup_stream(
+        &ctx.accounts.stream_counter,
+        &ctx.accounts.token_mint.to_account_info(),
+        start_time,
+        end_time,
+        amount,
+    )?;
+
+    msg!("Validation successful ✅ Creating stream... ⏳");
+
+    let stream_counter = &mut ctx.accounts.stream_counter;
+    let stream_index = stream_counter.stream_index;
+
+    // Prepare amounts struct
+    let amounts = Amounts {
+        deposited: amount,
+        protocol_fee: 0,
+        refunded: 0,
+        withdrawn: 0,
+    };
+
+    // Initialize stream account
+    *ctx.accounts.stream = LockupTranchedStream {
+        base_stream: BaseStream {
+            index: stream_index,
+            bump: ctx.bumps.stream,
+            treasury_bump: ctx.bumps.treasury_token_account,
+            sender: *ctx.accounts.sender.key,
+            token_mint: ctx.accounts.token_mint.key(),
+            is_canceled: false,
+            name: name.clone(),
+            amounts,
+            start_time,
+            end_time,
+            is_cancelable,
+            is_transferable,
+            recipient,
+        },
+        tranches,
+    };
+    msg!(
+        "LockupTranchedStream created with index: {} ✨",
+        stream_index
+    );
+    emit!(events::CreateLockupTranchedStream::new(
+        ctx.accounts.stream.key(),
+        &ctx.accounts.stream
+    ));
+
+    fund_lockup_stream(
+        &ctx.accounts.sender,
+        &ctx.accounts.sender_token_account,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // Increment stream counter
+    stream_counter.stream_index = stream_counter
+        .stream_index
+        .checked_add(1)
+        .ok_or(Error::Validation::Stream::ArithmeticOverflow)?;
+    msg!(
+        "Stream index incremented to {} 🧮",
+        stream_counter.stream_index
+    );
+
+    Ok(())
+}
+
+/// Validates the tranche count, timestamp ordering and that the tranches add up to the deposit.
+fn validate_tranches(start_time: i64, amount: u64, tranches: &[Tranche]) -> Result<()> {
+    require!(
+        !tranches.is_empty() && tranches.len() <= MAX_TRANCHE_COUNT,
+        Error::Validation::Stream::InvalidTrancheCount
+    );
+
+    let mut previous_timestamp = start_time;
+    let mut tranche_amounts: u64 = 0;
+    for tranche in tranches {
+        require!(
+            tranche.timestamp > previous_timestamp,
+            Error::Validation::Stream::InvalidTrancheTimestamps
+        );
+        previous_timestamp = tranche.timestamp;
+
+        tranche_amounts = tranche_amounts
+            .checked_add(tranche.amount)
+            .ok_or(Error::Validation::Stream::InvalidTrancheAmounts)?;
+    }
+
+    require!(
+        tranche_amounts == amount,
+        Error::Validation::Stream::InvalidTrancheAmounts
+    );
+
+    Ok(())
+}
+
+/// Context for creating a lockup tranched stream
+#[derive(Accounts)]
+pub struct CreateLockupTranchedStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = sender,
+        associated_token::token_program = token_program,
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [LOCKUP_TRANCHED_STREAM_COUNTER.as_ref()],
+        bump,
+    )]
+    pub stream_counter: Account<'info, StreamCounter>,
+
+    #[account(
+        init,
+        payer = sender,
+        token::mint = token_mint,
+        token::authority = treasury_token_account,
+        seeds = [
+            LOCKUP_TRANCHED_TREASURY.as_ref(),
+            token_mint.key().as_ref(),
+            &stream_counter.stream_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        space = ANCHOR_DISCRIMINATOR + LockupTranchedStream::INIT_SPACE,
+        payer = sender,
+        seeds = [LOCKUP_TRANCHED_STREAM.as_ref(), &stream_counter.stream_index.to_le_bytes()],
+        bump
+    )]
+    pub stream: Account<'info, LockupTranchedStream>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,30 @@
+use crate::{
+    initialize_stream_counter, seeds::LOCKUP_TRANCHED_STREAM_COUNTER, StreamCounter,
+    ANCHOR_DISCRIMINATOR,
+};
+use anchor_lang::prelude::*;
+
+/// Initializes the lockup tranched stream counter account.
+pub fn process_initialize_lockup_tranched_stream_counter(
+    ctx: Context<InitializeLockupTranchedStreamCounter>,
+) -> Result<()> {
+    initialize_stream_counter(ctx.accounts.sender.key(), &mut ctx.accounts.stream_counter)
+}
+
+/// Context for `initialize_lockup_tranched_stream_counter`.
+#[derive(Accounts)]
+pub struct InitializeLockupTranchedStreamCounter<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = ANCHOR_DISCRIMINATOR + StreamCounter::INIT_SPACE,
+        seeds = [LOCKUP_TRANCHED_STREAM_COUNTER.as_ref()],
+        bump
+    )]
+    pub stream_counter: Account<'info, StreamCounter>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,110 @@
+use crate::{error::Error, LockupTranchedStream};
+use anchor_lang::prelude::*;
+
+/// Computes the amount unlocked so far by summing the tranches whose timestamp has passed.
+///
+/// Once a stream is canceled, the unlocked amount is frozen at whatever was not refunded.
+pub fn get_lockup_tranched_streamed_amount(stream: &LockupTranchedStream, now: i64) -> Result<u64> {
+    let base_stream = &stream.base_stream;
+
+    if base_stream.is_canceled {
+        return base_stream
+            .amounts
+            .deposited
+            .checked_sub(base_stream.amounts.refunded)
+            .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow));
+    }
+
+    stream
+        .tranches
+        .iter()
+        .take_while(|tranche| tranche.timestamp <= now)
+        .try_fold(0u64, |unlocked_amount, tranche| {
+            unlocked_amount.checked_add(tranche.amount)
+        })
+        .ok_or(error!(Error::Validation::Stream::ArithmeticOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Amounts, BaseStream, Tranche};
+
+    fn lockup_tranched_stream(start_time: i64, tranches: Vec<Tranche>) -> LockupTranchedStream {
+        LockupTranchedStream {
+            base_stream: BaseStream {
+                index: 0,
+                bump: 0,
+                treasury_bump: 0,
+                name: "Test".to_string(),
+                sender: Pubkey::default(),
+                recipient: Pubkey::default(),
+                token_mint: Pubkey::default(),
+                amounts: Amounts {
+                    deposited: tranches.iter().map(|tranche| tranche.amount).sum(),
+                    protocol_fee: 0,
+                    refunded: 0,
+                    withdrawn: 0,
+                },
+                start_time,
+                end_time: tranches.last().unwrap().timestamp,
+                is_cancelable: true,
+                is_canceled: false,
+                is_transferable: true,
+            },
+            tranches,
+        }
+    }
+
+    #[test]
+    fn unlocks_each_tranche_at_its_timestamp() {
+        let stream = lockup_tranched_stream(
+            100,
+            vec![
+                Tranche {
+                    amount: 250,
+                    timestamp: 150,
+                },
+                Tranche {
+                    amount: 750,
+                    timestamp: 200,
+                },
+            ],
+        );
+
+        assert_eq!(
+            get_lockup_tranched_streamed_amount(&stream, 149).unwrap(),
+            0
+        );
+        assert_eq!(
+            get_lockup_tranched_streamed_amount(&stream, 150).unwrap(),
+            250
+        );
+        assert_eq!(
+            get_lockup_tranched_streamed_amount(&stream, 199).unwrap(),
+            250
+        );
+        assert_eq!(
+            get_lockup_tranched_streamed_amount(&stream, 200).unwrap(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn freezes_the_unlocked_amount_once_canceled() {
+        let mut stream = lockup_tranched_stream(
+            100,
+            vec![Tranche {
+                amount: 1_000,
+                timestamp: 200,
+            }],
+        );
+        stream.base_stream.is_canceled = true;
+        stream.base_stream.amounts.refunded = 1_000;
+
+        assert_eq!(
+            get_lockup_tranched_streamed_amount(&stream, 300).unwrap(),
+            0
+        );
+    }
+}
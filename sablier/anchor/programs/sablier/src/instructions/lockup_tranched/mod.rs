@@ -0,0 +1,17 @@
+pub use cancel::*;
+pub mod cancel;
+
+pub use create::*;
+pub mod create;
+
+pub use initialize::*;
+pub mod initialize;
+
+pub use math::*;
+pub mod math;
+
+pub use renounce::*;
+pub mod renounce;
+
+pub use withdraw::*;
+pub mod withdraw;
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    error::Error,
+    get_lockup_tranched_streamed_amount,
+    seeds::{LOCKUP_TRANCHED_STREAM, LOCKUP_TRANCHED_TREASURY},
+    validate_withdraw, withdraw_from_lockup_stream, LockupTranchedStream, LockupTreasuryTransfer,
+};
+
+pub fn process_withdraw_from_lockup_tranched_stream(
+    ctx: Context<WithdrawFromLockupTranchedStream>,
+    amount: u64,
+) -> Result<()> {
+    msg!("Validating Withdraw Operation... 🛂");
+    let now = Clock::get()?.unix_timestamp;
+    let status = ctx.accounts.stream.base_stream.status(false, now);
+    validate_withdraw(amount, status)?;
+
+    let streamed_amount = get_lockup_tranched_streamed_amount(&ctx.accounts.stream, now)?;
+
+    let stream_key = ctx.accounts.stream.key();
+    let mint_key = ctx.accounts.token_mint.key();
+    let stream_counter_index = ctx.accounts.stream.base_stream.index.to_le_bytes();
+    let treasury_bump = [ctx.accounts.stream.base_stream.treasury_bump];
+    let recipient = ctx.accounts.stream.base_stream.recipient;
+
+    withdraw_from_lockup_stream(
+        stream_key,
+        &mut ctx.accounts.stream.base_stream,
+        recipient,
+        streamed_amount,
+        amount,
+        LockupTreasuryTransfer {
+            treasury_token_account: &ctx.accounts.treasury_token_account,
+            destination_token_account: &ctx.accounts.recipient_token_account,
+            token_mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+            treasury_seeds: &[
+                LOCKUP_TRANCHED_TREASURY.as_ref(),
+                mint_key.as_ref(),
+                &stream_counter_index,
+                &treasury_bump,
+            ],
+        },
+    )
+}
+
+/// Accounts for `withdraw_from_lockup_tranched_stream`
+#[derive(Accounts)]
+pub struct WithdrawFromLockupTranchedStream<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_TRANCHED_STREAM.as_ref(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        constraint = stream.base_stream.recipient == recipient.key() @ Error::Authorization::Stream::UnauthorizedWithdraw,
+        bump = stream.base_stream.bump
+    )]
+    pub stream: Account<'info, LockupTranchedStream>,
+
+    #[account(
+        mut,
+        seeds = [
+            LOCKUP_TRANCHED_TREASURY.as_ref(),
+            token_mint.key().as_ref(),
+            &stream.base_stream.index.to_le_bytes(),
+        ],
+        bump = stream.base_stream.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = token_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,396 @@
+use crate::{
+    calculate_transfer_fee,
+    error::Error,
+    mint_lockup_linear_stream_nft,
+    seeds::{LOCKUP_LINEAR_NFT_MINT, LOCKUP_LINEAR_STREAM, LOCKUP_LINEAR_TREASURY},
+    Amounts, BaseStream, LegacyLockupLinearStream, LockupLinearStream, MintLockupLinearStreamNft,
+    ANCHOR_DISCRIMINATOR,
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+/// Migrates a lockup linear stream created before streams stored their numeric index and bumps.
+///
+/// The stream id is parsed once here, so that no other instruction has to. The account is grown to
+/// the current layout at the signer's expense, the fields added since are left at their defaults,
+/// and the stream NFT is minted to the recipient. Anyone can trigger the migration.
+///
+/// The stream keeps its legacy address, but its tokens move to a treasury derived from the stream,
+/// like the ones of new streams, and the legacy treasury is closed to the sender.
+pub fn process_migrate_lockup_linear_stream(ctx: Context<MigrateLockupLinearStream>) -> Result<()> {
+    msg!("Validating Migrate Operation... 🛂");
+    let stream = &ctx.accounts.stream;
+    let migrated_stream = migrate_lockup_linear_stream_data(
+        stream.key(),
+        &stream.try_borrow_data()?,
+        ctx.accounts.nft_mint.key(),
+        ctx.bumps.treasury_token_account,
+    )?;
+    let base_stream = &migrated_stream.base_stream;
+    require!(
+        base_stream.recipient == ctx.accounts.recipient.key()
+            && base_stream.sender == ctx.accounts.sender.key()
+            && base_stream.token_mint == ctx.accounts.token_mint.key(),
+        Error::Validation::Stream::InvalidLegacyAccount
+    );
+
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let stream_counter_index = base_stream.index.to_le_bytes();
+    let (legacy_treasury_address, legacy_treasury_bump) = Pubkey::find_program_address(
+        &[
+            LOCKUP_LINEAR_TREASURY,
+            token_mint_key.as_ref(),
+            &stream_counter_index,
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        legacy_treasury_address,
+        ctx.accounts.legacy_treasury_token_account.key(),
+        Error::Validation::Stream::InvalidLegacyAccount
+    );
+
+    let treasury_balance = ctx.accounts.legacy_treasury_token_account.amount;
+    require!(
+        calculate_transfer_fee(&ctx.accounts.token_mint.to_account_info(), treasury_balance)? == 0,
+        Error::Validation::Stream::UnsupportedTransferFee
+    );
+    msg!("Validation successful ✅ Migrating stream... ⏳");
+
+    // Move the tokens out of the legacy treasury, which is then closed to the sender
+    let legacy_treasury_seeds: &[&[u8]] = &[
+        LOCKUP_LINEAR_TREASURY,
+        token_mint_key.as_ref(),
+        &stream_counter_index,
+        &[legacy_treasury_bump],
+    ];
+    let signer_seeds = &[legacy_treasury_seeds];
+    if treasury_balance > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.legacy_treasury_token_account.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            authority: ctx.accounts.legacy_treasury_token_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+            .with_signer(signer_seeds);
+        transfer_checked(cpi_ctx, treasury_balance, ctx.accounts.token_mint.decimals)?;
+        msg!(
+            "Moved {} tokens to the stream treasury 💸",
+            treasury_balance
+        );
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.legacy_treasury_token_account.to_account_info(),
+        destination: ctx.accounts.sender.to_account_info(),
+        authority: ctx.accounts.legacy_treasury_token_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+        .with_signer(signer_seeds);
+    close_account(cpi_ctx)?;
+    msg!("Legacy treasury closed 🔒");
+
+    // Top up the rent of the stream account before growing it to the current layout
+    let space = ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE;
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(stream.lamports());
+    if rent_shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.signer.to_account_info(),
+            to: stream.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        transfer(cpi_ctx, rent_shortfall)?;
+    }
+    stream.realloc(space, false)?;
+
+    let mut data = stream.try_borrow_mut_data()?;
+    data.fill(0);
+    migrated_stream.try_serialize(&mut &mut data[..])?;
+    drop(data);
+    msg!(
+        "Stream migrated to index {} 🔁",
+        migrated_stream.base_stream.index
+    );
+
+    mint_lockup_linear_stream_nft(
+        MintLockupLinearStreamNft {
+            payer: ctx.accounts.signer.to_account_info(),
+            nft_mint: ctx.accounts.nft_mint.to_account_info(),
+            recipient_nft_token_account: ctx.accounts.recipient_nft_token_account.to_account_info(),
+            nft_token_program: ctx.accounts.nft_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        stream.key(),
+        &migrated_stream,
+        ctx.bumps.nft_mint,
+    )
+}
+
+/// Converts the data of a legacy lockup linear stream account into the current layout.
+///
+/// Only accounts that still have their legacy size are accepted, so that a stream cannot be
+/// migrated twice.
+fn migrate_lockup_linear_stream_data(
+    stream_key: Pubkey,
+    data: &[u8],
+    nft_mint: Pubkey,
+    treasury_bump: u8,
+) -> Result<LockupLinearStream> {
+    require!(
+        data.len() == ANCHOR_DISCRIMINATOR + LegacyLockupLinearStream::INIT_SPACE
+            && data[..ANCHOR_DISCRIMINATOR] == LockupLinearStream::DISCRIMINATOR,
+        Error::Validation::Stream::InvalidLegacyAccount
+    );
+    let legacy_stream = LegacyLockupLinearStream::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])
+        .map_err(|_| error!(Error::Validation::Stream::InvalidLegacyAccount))?;
+    let legacy_base_stream = legacy_stream.base_stream;
+
+    let index = parse_legacy_stream_id(&legacy_base_stream.id)?;
+    let stream_counter_index = index.to_le_bytes();
+
+    let (stream_address, bump) =
+        Pubkey::find_program_address(&[LOCKUP_LINEAR_STREAM, &stream_counter_index], &crate::ID);
+    require_keys_eq!(
+        stream_address,
+        stream_key,
+        Error::Validation::Stream::InvalidLegacyAccount
+    );
+
+    let legacy_amounts = legacy_base_stream.amounts;
+    Ok(LockupLinearStream {
+        base_stream: BaseStream {
+            index,
+            bump,
+            treasury_bump,
+            name: legacy_base_stream.name,
+            sender: legacy_base_stream.sender,
+            recipient: legacy_base_stream.recipient,
+            token_mint: legacy_base_stream.token_mint,
+            amounts: Amounts {
+                deposited: legacy_amounts.deposited,
+                protocol_fee: 0,
+                refunded: legacy_amounts.refunded,
+                withdrawn: legacy_amounts.withdrawn,
+            },
+            start_time: legacy_base_stream.start_time,
+            end_time: legacy_base_stream.end_time,
+            is_cancelable: legacy_base_stream.is_cancelable,
+            is_canceled: legacy_base_stream.is_canceled,
+            is_transferable: legacy_base_stream.is_transferable,
+        },
+        cliff_time: legacy_stream.cliff_time,
+        nft_mint,
+        paused_time: None,
+        paused_duration: 0,
+        operator_approval: None,
+        start_unlock: 0,
+        cliff_unlock: 0,
+        has_legacy_address: true,
+        // The sender paid the rent of the stream account when creating it
+        rent_payer: legacy_base_stream.sender,
+    })
+}
+
+/// Parses the index out of a legacy stream id in the `<prefix>-<index>` format.
+fn parse_legacy_stream_id(id: &str) -> Result<u64> {
+    id.split_once('-')
+        .and_then(|(_, index)| index.parse::<u64>().ok())
+        .ok_or(error!(Error::Validation::Stream::InvalidStreamIdFormat))
+}
+
+/// Accounts for `migrate_lockup_linear_stream`
+#[derive(Accounts)]
+pub struct MigrateLockupLinearStream<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Deserialized manually from the legacy layout and verified as the PDA of its stream id
+    #[account(mut, owner = crate::ID)]
+    pub stream: UncheckedAccount<'info>,
+
+    /// CHECK: Verified against the recipient stored in the legacy stream
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Verified against the sender stored in the legacy stream, receives the rent of the legacy treasury
+    #[account(mut)]
+    pub sender: UncheckedAccount<'info>,
+
+    /// The treasury the stream was created with, verified as the PDA of its token mint and stream id
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+    )]
+    pub legacy_treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        token::mint = token_mint,
+        token::authority = treasury_token_account,
+        token::token_program = token_program,
+        seeds = [LOCKUP_LINEAR_TREASURY.as_ref(), stream.key().as_ref()],
+        bump
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        mint::decimals = 0,
+        mint::authority = nft_mint,
+        mint::freeze_authority = nft_mint,
+        mint::token_program = nft_token_program,
+        extensions::metadata_pointer::authority = nft_mint,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+        seeds = [LOCKUP_LINEAR_NFT_MINT.as_ref(), stream.key().as_ref()],
+        bump
+    )]
+    pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = nft_token_program,
+    )]
+    pub recipient_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Program of the stream NFT, which holds its metadata as a Token-2022 extension
+    pub nft_token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: Pubkey = Pubkey::new_from_array([1; 32]);
+    const RECIPIENT: Pubkey = Pubkey::new_from_array([2; 32]);
+    const TOKEN_MINT: Pubkey = Pubkey::new_from_array([3; 32]);
+
+    /// Writes a lockup linear stream account byte by byte, the way the legacy program laid it out.
+    fn legacy_lockup_linear_stream_data(id: &str, cliff_time: i64) -> Vec<u8> {
+        let mut data = LockupLinearStream::DISCRIMINATOR.to_vec();
+        for string in [id, "Test"] {
+            data.extend((string.len() as u32).to_le_bytes());
+            data.extend(string.as_bytes());
+        }
+        for pubkey in [SENDER, RECIPIENT, TOKEN_MINT] {
+            data.extend(pubkey.to_bytes());
+        }
+        // Deposited, refunded and withdrawn amounts
+        for amount in [1_000u64, 0, 250] {
+            data.extend(amount.to_le_bytes());
+        }
+        // Start and end times
+        for time in [100i64, 200] {
+            data.extend(time.to_le_bytes());
+        }
+        // Cancelable, canceled and transferable flags
+        data.extend([1, 0, 1]);
+        data.extend(cliff_time.to_le_bytes());
+
+        // Accounts were allocated for ids and names of 32 bytes
+        data.resize(
+            ANCHOR_DISCRIMINATOR + 2 * (4 + 32) + 3 * 32 + 3 * 8 + 2 * 8 + 3 + 8,
+            0,
+        );
+        data
+    }
+
+    #[test]
+    fn migrates_a_legacy_lockup_linear_stream() {
+        let nft_mint = Pubkey::new_unique();
+        let data = legacy_lockup_linear_stream_data("LL-5", 150);
+        let (stream_key, bump) =
+            Pubkey::find_program_address(&[LOCKUP_LINEAR_STREAM, &5u64.to_le_bytes()], &crate::ID);
+
+        let stream = migrate_lockup_linear_stream_data(stream_key, &data, nft_mint, 254).unwrap();
+        assert_eq!(stream.base_stream.index, 5);
+        assert_eq!(stream.base_stream.bump, bump);
+        assert_eq!(stream.base_stream.treasury_bump, 254);
+        assert!(stream.has_legacy_address);
+
+        // The seeds checked by the other instructions still resolve to the legacy address
+        let derived_key = Pubkey::create_program_address(
+            &[
+                LOCKUP_LINEAR_STREAM,
+                stream.sender_seed(),
+                &stream.base_stream.index.to_le_bytes(),
+                &[stream.base_stream.bump],
+            ],
+            &crate::ID,
+        )
+        .unwrap();
+        assert_eq!(derived_key, stream_key);
+        assert_eq!(stream.base_stream.name, "Test");
+        assert_eq!(stream.base_stream.sender, SENDER);
+        assert_eq!(stream.base_stream.recipient, RECIPIENT);
+        assert_eq!(stream.base_stream.token_mint, TOKEN_MINT);
+        assert_eq!(stream.base_stream.amounts.deposited, 1_000);
+        assert_eq!(stream.base_stream.amounts.protocol_fee, 0);
+        assert_eq!(stream.base_stream.amounts.withdrawn, 250);
+        assert_eq!(stream.base_stream.end_time, 200);
+        assert!(stream.base_stream.is_transferable);
+        assert_eq!(stream.cliff_time, 150);
+        assert_eq!(stream.nft_mint, nft_mint);
+
+        // The migrated stream fits the current layout
+        let mut migrated_data = Vec::new();
+        stream.try_serialize(&mut migrated_data).unwrap();
+        assert!(migrated_data.len() <= ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE);
+    }
+
+    #[test]
+    fn rejects_a_stream_that_does_not_match_its_id() {
+        let data = legacy_lockup_linear_stream_data("LL-5", 150);
+
+        assert!(migrate_lockup_linear_stream_data(
+            Pubkey::new_unique(),
+            &data,
+            Pubkey::new_unique(),
+            254
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_a_stream_that_was_already_migrated() {
+        let (stream_key, _) =
+            Pubkey::find_program_address(&[LOCKUP_LINEAR_STREAM, &5u64.to_le_bytes()], &crate::ID);
+        let mut data = legacy_lockup_linear_stream_data("LL-5", 150);
+        data.resize(ANCHOR_DISCRIMINATOR + LockupLinearStream::INIT_SPACE, 0);
+
+        assert!(
+            migrate_lockup_linear_stream_data(stream_key, &data, Pubkey::new_unique(), 254)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_ids_without_panicking() {
+        assert!(parse_legacy_stream_id("LL-5").is_ok());
+        assert!(parse_legacy_stream_id("LL5").is_err());
+        assert!(parse_legacy_stream_id("LL-").is_err());
+        assert!(parse_legacy_stream_id("LL-x").is_err());
+    }
+}
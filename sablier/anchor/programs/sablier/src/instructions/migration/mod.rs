@@ -0,0 +1,2 @@
+pub use migrate_stream::*;
+pub mod migrate_stream;
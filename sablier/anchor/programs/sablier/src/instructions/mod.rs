@@ -1,8 +1,23 @@
 pub use base::*;
 pub mod base;
 
+pub use campaign::*;
+pub mod campaign;
+
+pub use config::*;
+pub mod config;
+
+pub use flow::*;
+pub mod flow;
+
+pub use lockup_dynamic::*;
+pub mod lockup_dynamic;
+
 pub use lockup_linear::*;
 pub mod lockup_linear;
 
-pub use utils::*;
-pub mod utils;
+pub use lockup_tranched::*;
+pub mod lockup_tranched;
+
+pub use migration::*;
+pub mod migration;
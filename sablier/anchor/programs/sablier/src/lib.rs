@@ -7,6 +7,7 @@ use state::*;
 
 mod constants;
 mod error;
+mod events;
 mod instructions;
 mod seeds;
 mod state;
@@ -17,50 +18,322 @@ declare_id!("7bQwRRQErWVNRczPTGbg7rtALWCHDhGn8sUvkAZYFt4d");
 pub mod sablier {
     use super::*;
 
-    pub fn initialize_lockup_linear_stream_counter(
-        ctx: Context<InitializeLockupLinearStreamCounter>,
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        fee_recipient: Pubkey,
+        protocol_fee_bps: u16,
     ) -> Result<()> {
-        process_initialize_lockup_linear_stream_counter(ctx)
+        process_initialize_config(ctx, fee_recipient, protocol_fee_bps)
+    }
+
+    pub fn set_protocol_fee(
+        ctx: Context<SetProtocolFee>,
+        fee_recipient: Pubkey,
+        protocol_fee_bps: u16,
+    ) -> Result<()> {
+        process_set_protocol_fee(ctx, fee_recipient, protocol_fee_bps)
+    }
+
+    pub fn collect_protocol_fees(ctx: Context<CollectProtocolFees>) -> Result<()> {
+        process_collect_protocol_fees(ctx)
+    }
+
+    pub fn register_stream_hook(
+        ctx: Context<RegisterStreamHook>,
+        account: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        process_register_stream_hook(ctx, account, program_id)
+    }
+
+    pub fn unregister_stream_hook(ctx: Context<UnregisterStreamHook>) -> Result<()> {
+        process_unregister_stream_hook(ctx)
     }
 
     pub fn create_lockup_linear_stream(
         ctx: Context<CreateLockupLinearStream>,
         name: String,
-        recipient: Pubkey,
         amount: u64,
         start_time: i64,
         end_time: i64,
         cliff_time: i64,
         is_cancelable: bool,
         is_transferable: bool,
+        broker_fee_bps: u16,
+        start_unlock: Option<u64>,
+        cliff_unlock: Option<u64>,
     ) -> Result<()> {
         process_create_lockup_linear_stream(
             ctx,
             name,
-            recipient,
             amount,
             start_time,
             end_time,
             cliff_time,
             is_cancelable,
             is_transferable,
+            broker_fee_bps,
+            start_unlock,
+            cliff_unlock,
         )
     }
 
-    pub fn cancel_lockup_linear_stream(ctx: Context<CancelLockupLinearStream>) -> Result<()> {
+    pub fn create_lockup_linear_streams_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateLockupLinearStreamsBatch<'info>>,
+        entries: Vec<CreateLockupLinearStreamEntry>,
+    ) -> Result<()> {
+        process_create_lockup_linear_streams_batch(ctx, entries)
+    }
+
+    pub fn cancel_lockup_linear_stream<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelLockupLinearStream<'info>>,
+    ) -> Result<()> {
         process_cancel_lockup_linear_stream(ctx)
     }
 
+    pub fn close_lockup_linear_stream(ctx: Context<CloseLockupLinearStream>) -> Result<()> {
+        process_close_lockup_linear_stream(ctx)
+    }
+
+    pub fn approve_lockup_linear_stream_operator(
+        ctx: Context<ApproveLockupLinearStreamOperator>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        process_approve_lockup_linear_stream_operator(ctx, operator)
+    }
+
+    pub fn revoke_lockup_linear_stream_operator(
+        ctx: Context<RevokeLockupLinearStreamOperator>,
+    ) -> Result<()> {
+        process_revoke_lockup_linear_stream_operator(ctx)
+    }
+
+    pub fn pause_lockup_linear_stream(ctx: Context<PauseLockupLinearStream>) -> Result<()> {
+        process_pause_lockup_linear_stream(ctx)
+    }
+
+    pub fn resume_lockup_linear_stream(ctx: Context<ResumeLockupLinearStream>) -> Result<()> {
+        process_resume_lockup_linear_stream(ctx)
+    }
+
     pub fn renounce_cancelability_lockup_linear_stream(
         ctx: Context<RenounceCancelabilityLockupLinearStream>,
     ) -> Result<()> {
         process_renounce_cancelability_lockup_linear_stream(ctx)
     }
 
-    pub fn withdraw_from_lockup_linear_stream(
-        ctx: Context<WithdrawFromLockupLinearStream>,
+    pub fn top_up_lockup_linear_stream(
+        ctx: Context<TopUpLockupLinearStream>,
+        amount: u64,
+        mode: TopUpMode,
+    ) -> Result<()> {
+        process_top_up_lockup_linear_stream(ctx, amount, mode)
+    }
+
+    pub fn transfer_lockup_linear_stream(ctx: Context<TransferLockupLinearStream>) -> Result<()> {
+        process_transfer_lockup_linear_stream(ctx)
+    }
+
+    pub fn withdraw_from_lockup_linear_stream<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFromLockupLinearStream<'info>>,
         amount: u64,
     ) -> Result<()> {
         process_withdraw_from_lockup_linear_stream(ctx, amount)
     }
+
+    pub fn withdraw_from_lockup_linear_stream_to<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFromLockupLinearStreamTo<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        process_withdraw_from_lockup_linear_stream_to(ctx, amount)
+    }
+
+    pub fn withdraw_max_from_lockup_linear_stream<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFromLockupLinearStream<'info>>,
+    ) -> Result<()> {
+        process_withdraw_max_from_lockup_linear_stream(ctx)
+    }
+
+    pub fn streamed_amount_of(ctx: Context<LockupLinearStreamView>) -> Result<u64> {
+        process_streamed_amount_of(ctx)
+    }
+
+    pub fn withdrawable_amount_of(ctx: Context<LockupLinearStreamView>) -> Result<u64> {
+        process_withdrawable_amount_of(ctx)
+    }
+
+    pub fn refundable_amount_of(ctx: Context<LockupLinearStreamView>) -> Result<u64> {
+        process_refundable_amount_of(ctx)
+    }
+
+    pub fn status_of(ctx: Context<LockupLinearStreamView>) -> Result<StreamStatus> {
+        process_status_of(ctx)
+    }
+
+    pub fn initialize_lockup_dynamic_stream_counter(
+        ctx: Context<InitializeLockupDynamicStreamCounter>,
+    ) -> Result<()> {
+        process_initialize_lockup_dynamic_stream_counter(ctx)
+    }
+
+    pub fn create_lockup_dynamic_stream(
+        ctx: Context<CreateLockupDynamicStream>,
+        name: String,
+        recipient: Pubkey,
+        amount: u64,
+        start_time: i64,
+        segments: Vec<Segment>,
+        is_cancelable: bool,
+        is_transferable: bool,
+    ) -> Result<()> {
+        process_create_lockup_dynamic_stream(
+            ctx,
+            name,
+            recipient,
+            amount,
+            start_time,
+            segments,
+            is_cancelable,
+            is_transferable,
+        )
+    }
+
+    pub fn cancel_lockup_dynamic_stream(ctx: Context<CancelLockupDynamicStream>) -> Result<()> {
+        process_cancel_lockup_dynamic_stream(ctx)
+    }
+
+    pub fn renounce_cancelability_lockup_dynamic_stream(
+        ctx: Context<RenounceCancelabilityLockupDynamicStream>,
+    ) -> Result<()> {
+        process_renounce_cancelability_lockup_dynamic_stream(ctx)
+    }
+
+    pub fn withdraw_from_lockup_dynamic_stream(
+        ctx: Context<WithdrawFromLockupDynamicStream>,
+        amount: u64,
+    ) -> Result<()> {
+        process_withdraw_from_lockup_dynamic_stream(ctx, amount)
+    }
+
+    pub fn initialize_lockup_tranched_stream_counter(
+        ctx: Context<InitializeLockupTranchedStreamCounter>,
+    ) -> Result<()> {
+        process_initialize_lockup_tranched_stream_counter(ctx)
+    }
+
+    pub fn create_lockup_tranched_stream(
+        ctx: Context<CreateLockupTranchedStream>,
+        name: String,
+        recipient: Pubkey,
+        amount: u64,
+        start_time: i64,
+        tranches: Vec<Tranche>,
+        is_cancelable: bool,
+        is_transferable: bool,
+    ) -> Result<()> {
+        process_create_lockup_tranched_stream(
+            ctx,
+            name,
+            recipient,
+            amount,
+            start_time,
+            tranches,
+            is_cancelable,
+            is_transferable,
+        )
+    }
+
+    pub fn cancel_lockup_tranched_stream(ctx: Context<CancelLockupTranchedStream>) -> Result<()> {
+        process_cancel_lockup_tranched_stream(ctx)
+    }
+
+    pub fn renounce_cancelability_lockup_tranched_stream(
+        ctx: Context<RenounceCancelabilityLockupTranchedStream>,
+    ) -> Result<()> {
+        process_renounce_cancelability_lockup_tranched_stream(ctx)
+    }
+
+    pub fn withdraw_from_lockup_tranched_stream(
+        ctx: Context<WithdrawFromLockupTranchedStream>,
+        amount: u64,
+    ) -> Result<()> {
+        process_withdraw_from_lockup_tranched_stream(ctx, amount)
+    }
+
+    pub fn migrate_lockup_linear_stream(ctx: Context<MigrateLockupLinearStream>) -> Result<()> {
+        process_migrate_lockup_linear_stream(ctx)
+    }
+
+    pub fn create_flow_stream(
+        ctx: Context<CreateFlowStream>,
+        name: String,
+        recipient: Pubkey,
+        rate_per_second: u64,
+    ) -> Result<()> {
+        process_create_flow_stream(ctx, name, recipient, rate_per_second)
+    }
+
+    pub fn deposit_into_flow_stream(
+        ctx: Context<DepositIntoFlowStream>,
+        amount: u64,
+    ) -> Result<()> {
+        process_deposit_into_flow_stream(ctx, amount)
+    }
+
+    pub fn adjust_flow_stream_rate(
+        ctx: Context<AdjustFlowStreamRate>,
+        rate_per_second: u64,
+    ) -> Result<()> {
+        process_adjust_flow_stream_rate(ctx, rate_per_second)
+    }
+
+    pub fn pause_flow_stream(ctx: Context<PauseFlowStream>) -> Result<()> {
+        process_pause_flow_stream(ctx)
+    }
+
+    pub fn restart_flow_stream(
+        ctx: Context<RestartFlowStream>,
+        rate_per_second: u64,
+    ) -> Result<()> {
+        process_restart_flow_stream(ctx, rate_per_second)
+    }
+
+    pub fn withdraw_from_flow_stream(
+        ctx: Context<WithdrawFromFlowStream>,
+        amount: u64,
+    ) -> Result<()> {
+        process_withdraw_from_flow_stream(ctx, amount)
+    }
+
+    pub fn refund_from_flow_stream(ctx: Context<RefundFromFlowStream>, amount: u64) -> Result<()> {
+        process_refund_from_flow_stream(ctx, amount)
+    }
+
+    pub fn void_flow_stream(ctx: Context<VoidFlowStream>) -> Result<()> {
+        process_void_flow_stream(ctx)
+    }
+
+    pub fn create_campaign(
+        ctx: Context<CreateCampaign>,
+        name: String,
+        merkle_root: [u8; 32],
+        amount: u64,
+        expiry_time: i64,
+        stream_template: StreamTemplate,
+    ) -> Result<()> {
+        process_create_campaign(ctx, name, merkle_root, amount, expiry_time, stream_template)
+    }
+
+    pub fn claim_from_campaign(
+        ctx: Context<ClaimFromCampaign>,
+        index: u64,
+        amount: u64,
+        merkle_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        process_claim_from_campaign(ctx, index, amount, merkle_proof)
+    }
+
+    pub fn clawback_from_campaign(ctx: Context<ClawbackFromCampaign>) -> Result<()> {
+        process_clawback_from_campaign(ctx)
+    }
 }
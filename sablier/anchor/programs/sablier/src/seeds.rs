@@ -2,8 +2,39 @@
 /// To be used with .as_ref() everywhere to accommodate varying seed lengths
 ///
 
+/// -------------------------- Config Seeds -------------------------- ///
+
+pub const CONFIG: &[u8] = b"Config";
+pub const PROTOCOL_FEE_VAULT: &[u8] = b"ProtocolFeeVault";
+pub const STREAM_HOOK: &[u8] = b"StreamHook";
+
 /// -------------------------- LockupLinear Seeds -------------------------- ///
 
 pub const LOCKUP_LINEAR_STREAM: &[u8] = b"LockupLinearStream";
 pub const LOCKUP_LINEAR_TREASURY: &[u8] = b"LockupLinearTreasury";
 pub const LOCKUP_LINEAR_STREAM_COUNTER: &[u8] = b"LockupLinearStreamCounter";
+pub const LOCKUP_LINEAR_NFT_MINT: &[u8] = b"LockupLinearNftMint";
+
+/// -------------------------- LockupDynamic Seeds -------------------------- ///
+
+pub const LOCKUP_DYNAMIC_STREAM: &[u8] = b"LockupDynamicStream";
+pub const LOCKUP_DYNAMIC_TREASURY: &[u8] = b"LockupDynamicTreasury";
+pub const LOCKUP_DYNAMIC_STREAM_COUNTER: &[u8] = b"LockupDynamicStreamCounter";
+
+/// -------------------------- LockupTranched Seeds -------------------------- ///
+
+pub const LOCKUP_TRANCHED_STREAM: &[u8] = b"LockupTranchedStream";
+pub const LOCKUP_TRANCHED_TREASURY: &[u8] = b"LockupTranchedTreasury";
+pub const LOCKUP_TRANCHED_STREAM_COUNTER: &[u8] = b"LockupTranchedStreamCounter";
+
+/// -------------------------- Flow Seeds -------------------------- ///
+
+pub const FLOW_STREAM: &[u8] = b"FlowStream";
+pub const FLOW_TREASURY: &[u8] = b"FlowTreasury";
+pub const FLOW_STREAM_COUNTER: &[u8] = b"FlowStreamCounter";
+
+/// -------------------------- Campaign Seeds -------------------------- ///
+
+pub const CAMPAIGN: &[u8] = b"Campaign";
+pub const CAMPAIGN_TREASURY: &[u8] = b"CampaignTreasury";
+pub const CAMPAIGN_CLAIM_RECEIPT: &[u8] = b"CampaignClaimReceipt";
@@ -4,6 +4,61 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct BaseStream {
+    /// Index of the stream within its stream counter, from which the stream's PDAs are derived.
+    pub index: u64,
+    pub bump: u8,
+    pub treasury_bump: u8,
+
+    #[max_len(32)]
+    pub name: String,
+
+    pub sender: Pubkey,
+    /// Recipient of the stream.
+    ///
+    /// For lockup linear streams, the holder of the stream NFT is the actual recipient, and this is
+    /// only the last holder known to the program: it goes stale when the NFT changes hands outside
+    /// of `transfer_lockup_linear_stream`, so it must not be relied upon for authorization.
+    pub recipient: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amounts: Amounts,
+
+    pub start_time: i64,
+    pub end_time: i64,
+
+    pub is_cancelable: bool,
+    pub is_canceled: bool,
+    pub is_transferable: bool,
+}
+
+impl BaseStream {
+    /// Computes the status of the stream at the given time.
+    ///
+    /// A stream settles at its end time, since every stream kind has streamed its whole deposit by then.
+    pub fn status(&self, is_paused: bool, now: i64) -> StreamStatus {
+        let amounts = &self.amounts;
+        if amounts.withdrawn.saturating_add(amounts.refunded) >= amounts.deposited {
+            StreamStatus::Depleted
+        } else if self.is_canceled {
+            StreamStatus::Canceled
+        } else if is_paused {
+            StreamStatus::Paused
+        } else if now < self.start_time {
+            StreamStatus::Pending
+        } else if now >= self.end_time {
+            StreamStatus::Settled
+        } else {
+            StreamStatus::Streaming
+        }
+    }
+}
+
+/// Layout of `BaseStream` from before streams stored their numeric index and bumps,
+/// frozen to migrate the accounts created with it.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyBaseStream {
+    /// Stream id in the `<prefix>-<index>` format, e.g. `LL-5`.
     #[max_len(32)]
     pub id: String,
     #[max_len(32)]
@@ -14,7 +69,7 @@ pub struct BaseStream {
 
     pub token_mint: Pubkey,
 
-    pub amounts: Amounts,
+    pub amounts: LegacyAmounts,
 
     pub start_time: i64,
     pub end_time: i64,
@@ -24,14 +79,41 @@ pub struct BaseStream {
     pub is_transferable: bool,
 }
 
-/// Holds the deposited, refunded, and withdrawn token amounts.
+/// Layout of `Amounts` from before the protocol fee was recorded, frozen along with `LegacyBaseStream`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyAmounts {
+    pub deposited: u64,
+    pub refunded: u64,
+    pub withdrawn: u64,
+}
+
+/// Holds the deposited, refunded, and withdrawn token amounts, along with the protocol fee
+/// deducted from the amount paid for the stream, so that `deposited` is net of it.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Amounts {
     pub deposited: u64,
+    pub protocol_fee: u64,
     pub refunded: u64,
     pub withdrawn: u64,
 }
 
+/// Lifecycle status of a stream, computed from its amounts, flags and the current time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamStatus {
+    /// The start time has not been reached yet.
+    Pending,
+    /// Tokens are being streamed.
+    Streaming,
+    /// Streaming is frozen until the sender resumes the stream.
+    Paused,
+    /// Everything was streamed, but not everything was withdrawn yet.
+    Settled,
+    /// The stream was canceled, but the recipient has not withdrawn their share yet.
+    Canceled,
+    /// Everything was either withdrawn or refunded.
+    Depleted,
+}
+
 /// Maintains a running index for stream creation.
 #[account]
 #[derive(InitSpace)]
@@ -0,0 +1,37 @@
+use crate::MAX_CAMPAIGN_NAME_LENGTH;
+use anchor_lang::prelude::*;
+
+/// An airdrop campaign that creates a lockup linear stream for every eligible wallet that claims.
+#[account]
+#[derive(InitSpace)]
+pub struct Campaign {
+    #[max_len(MAX_CAMPAIGN_NAME_LENGTH)]
+    pub name: String,
+
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+
+    /// Root of the Merkle tree whose leaves are `keccak(index || claimant || amount)`.
+    pub merkle_root: [u8; 32],
+    /// Time after which claims are rejected and the creator can claw back the remaining funds.
+    pub expiry_time: i64,
+
+    pub stream_template: StreamTemplate,
+}
+
+/// Vesting parameters applied to every stream created by a campaign, relative to the claim time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct StreamTemplate {
+    pub cliff_duration: i64,
+    pub total_duration: i64,
+    pub is_cancelable: bool,
+    pub is_transferable: bool,
+}
+
+/// Marks a campaign leaf as claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimReceipt {
+    pub claimant: Pubkey,
+    pub stream: Pubkey,
+}
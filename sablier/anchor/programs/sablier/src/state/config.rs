@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Global protocol settings, managed by the Sablier Team.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub authority: Pubkey,
+    /// Wallet that receives the protocol fees collected from the fee vaults.
+    pub fee_recipient: Pubkey,
+    /// Share of every deposit taken as protocol fee, in basis points.
+    pub protocol_fee_bps: u16,
+}
+
+/// Allowlists the program to call back whenever tokens are withdrawn to, or refunded to, the given account.
+///
+/// The account is either the hook program itself or one of its PDAs, such as a vault.
+#[account]
+#[derive(InitSpace)]
+pub struct StreamHook {
+    pub account: Pubkey,
+    pub program_id: Pubkey,
+    pub bump: u8,
+}
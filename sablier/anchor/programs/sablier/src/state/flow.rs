@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// An open-ended flow stream, which streams tokens at a rate per second with no end time.
+///
+/// The debt owed to the recipient is the snapshot debt plus whatever accrued since the snapshot
+/// time, while the balance holds the deposited tokens that have not been withdrawn or refunded.
+/// Any debt above the balance is uncovered until the stream is topped up with a deposit.
+#[account]
+#[derive(InitSpace)]
+pub struct FlowStream {
+    /// Index of the stream within its sender's stream counter, from which the stream's PDA is derived.
+    pub index: u64,
+    pub bump: u8,
+    pub treasury_bump: u8,
+
+    #[max_len(32)]
+    pub name: String,
+
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    /// Amount streamed per second, in the token's base units, which is 0 while the stream is paused.
+    pub rate_per_second: u64,
+    pub snapshot_time: i64,
+    pub snapshot_debt: u64,
+    pub balance: u64,
+
+    pub is_voided: bool,
+}
@@ -0,0 +1,21 @@
+use super::BaseStream;
+use crate::MAX_SEGMENT_COUNT;
+use anchor_lang::prelude::*;
+
+/// A lockup dynamic stream
+#[account]
+#[derive(InitSpace)]
+pub struct LockupDynamicStream {
+    pub base_stream: BaseStream,
+    #[max_len(MAX_SEGMENT_COUNT)]
+    pub segments: Vec<Segment>,
+}
+
+/// A portion of a dynamic stream that streams `amount` until `milestone` following an exponential curve.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Segment {
+    pub amount: u64,
+    /// Fixed-point exponent, scaled by `SEGMENT_EXPONENT_SCALE`.
+    pub exponent: u64,
+    pub milestone: i64,
+}
@@ -1,4 +1,4 @@
-use super::BaseStream;
+use super::{BaseStream, LegacyBaseStream};
 use anchor_lang::prelude::*;
 
 /// A lockup linear stream
@@ -7,4 +7,59 @@ use anchor_lang::prelude::*;
 pub struct LockupLinearStream {
     pub base_stream: BaseStream,
     pub cliff_time: i64,
+    /// Mint of the NFT whose holder is entitled to withdraw from the stream.
+    pub nft_mint: Pubkey,
+    /// Time at which the stream was paused, if it is currently paused.
+    pub paused_time: Option<i64>,
+    /// Total time the stream spent paused, by which its schedule was pushed back on every resume.
+    pub paused_duration: i64,
+    /// Operator approved by the NFT holder to withdraw on their behalf, if any.
+    pub operator_approval: Option<OperatorApproval>,
+    /// Amount unlocked at the start time, ahead of the linear schedule.
+    pub start_unlock: u64,
+    /// Amount unlocked at the cliff time, ahead of the linear schedule.
+    pub cliff_unlock: u64,
+    /// Whether the stream was migrated from the global stream counter, whose addresses were not
+    /// derived from the sender.
+    pub has_legacy_address: bool,
+    /// Account that paid the rent of the stream and its treasury, which gets it back on close.
+    pub rent_payer: Pubkey,
+}
+
+/// Layout of `LockupLinearStream` from before streams stored their numeric index and bumps,
+/// frozen to migrate the accounts created with it.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyLockupLinearStream {
+    pub base_stream: LegacyBaseStream,
+    pub cliff_time: i64,
+}
+
+/// An operator allowed to withdraw from a stream, along with the NFT holder who approved them.
+///
+/// The approval only holds while the approver still owns the stream NFT, since the NFT can change
+/// hands outside of the program when the stream is transferable.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct OperatorApproval {
+    pub operator: Pubkey,
+    pub approver: Pubkey,
+}
+
+impl LockupLinearStream {
+    /// Returns the sender seed of the stream's address.
+    ///
+    /// Migrated streams keep their legacy address through an empty seed, which leaves the derived
+    /// address unchanged.
+    pub fn sender_seed(&self) -> &[u8] {
+        if self.has_legacy_address {
+            &[]
+        } else {
+            self.base_stream.sender.as_ref()
+        }
+    }
+
+    /// Checks whether `operator` was approved by `holder`, the current holder of the stream NFT.
+    pub fn is_approved_operator(&self, operator: Pubkey, holder: Pubkey) -> bool {
+        self.operator_approval
+            .is_some_and(|approval| approval.operator == operator && approval.approver == holder)
+    }
 }
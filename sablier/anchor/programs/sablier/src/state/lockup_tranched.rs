@@ -0,0 +1,19 @@
+use super::BaseStream;
+use crate::MAX_TRANCHE_COUNT;
+use anchor_lang::prelude::*;
+
+/// A lockup tranched stream
+#[account]
+#[derive(InitSpace)]
+pub struct LockupTranchedStream {
+    pub base_stream: BaseStream,
+    #[max_len(MAX_TRANCHE_COUNT)]
+    pub tranches: Vec<Tranche>,
+}
+
+/// A portion of a tranched stream that fully unlocks `amount` at `timestamp`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Tranche {
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -1,5 +1,15 @@
 import { BN } from '@coral-xyz/anchor';
-import { PublicKey } from '@solana/web3.js';
+import {
+	createAssociatedTokenAccountInstruction,
+	createInitializeMintInstruction,
+	createMintToInstruction,
+	ExtensionType,
+	getAssociatedTokenAddressSync,
+	getMintLen,
+	TOKEN_2022_PROGRAM_ID,
+} from '@solana/spl-token';
+import { Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from '@solana/web3.js';
+import { BankrunProvider } from 'anchor-bankrun';
 import { BanksClient, Clock, ProgramTestContext } from 'solana-bankrun';
 
 export async function timeTravelTo(timestamp: number, banksClient: BanksClient, context: ProgramTestContext) {
@@ -29,6 +39,44 @@ export async function timeTravelFor(amount: number, banksClient: BanksClient, co
 	);
 }
 
+// Creates a Token-2022 mint with the given extensions, initialized by `initializeExtensions`,
+// and mints `amount` tokens to the payer's associated token account
+export async function createToken2022Mint(
+	provider: BankrunProvider,
+	payer: Keypair,
+	extensions: ExtensionType[],
+	initializeExtensions: (mint: PublicKey) => TransactionInstruction[],
+	amount: number
+) {
+	const mint = Keypair.generate();
+	const mintLen = getMintLen(extensions);
+	const rent = await provider.context.banksClient.getRent();
+	const payerTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, payer.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+	const tx = new Transaction().add(
+		SystemProgram.createAccount({
+			fromPubkey: payer.publicKey,
+			newAccountPubkey: mint.publicKey,
+			space: mintLen,
+			lamports: Number(rent.minimumBalance(BigInt(mintLen))),
+			programId: TOKEN_2022_PROGRAM_ID,
+		}),
+		...initializeExtensions(mint.publicKey),
+		createInitializeMintInstruction(mint.publicKey, 9, payer.publicKey, null, TOKEN_2022_PROGRAM_ID),
+		createAssociatedTokenAccountInstruction(
+			payer.publicKey,
+			payerTokenAccount,
+			payer.publicKey,
+			mint.publicKey,
+			TOKEN_2022_PROGRAM_ID
+		),
+		createMintToInstruction(mint.publicKey, payerTokenAccount, payer.publicKey, amount, [], TOKEN_2022_PROGRAM_ID)
+	);
+	await provider.sendAndConfirm!(tx, [payer, mint]);
+
+	return { mint: mint.publicKey, payerTokenAccount };
+}
+
 export async function getTokenBalanceFor(account: PublicKey, client: BanksClient): Promise<BN> {
 	console.log('Getting token balance by account: ', account.toBase58());
 	const accountData = (await client.getAccount(account))?.data;
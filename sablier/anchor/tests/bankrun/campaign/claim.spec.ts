@@ -0,0 +1,142 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAccount, getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BankrunProvider } from 'anchor-bankrun';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { getStreamAddress, getStreamCounterIndex } from '../lockup-linear/utils';
+import { now } from '../stream-utils';
+import { beforeAllSetup } from '../lockup-linear/setup';
+import { Allocation, createCampaign } from './utils';
+
+describe('Campaign - Claim Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+	let provider: BankrunProvider;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	let allocations: Allocation[];
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program, provider } = await beforeAllSetup());
+
+		allocations = [
+			{ claimant: bob.publicKey, amount: 1_000 },
+			{ claimant: Keypair.generate().publicKey, amount: 2_000 },
+			{ claimant: Keypair.generate().publicKey, amount: 3_000 },
+		];
+	}, TIMEOUT);
+
+	const claim = (campaign: PublicKey, index: number, amount: number, proof: number[][]) => {
+		return program.methods
+			.claimFromCampaign(new BN(index), new BN(amount), proof)
+			.accounts({
+				campaign,
+				claimant: bob.publicKey,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([bob])
+			.rpc();
+	};
+
+	describe('Campaign - Claim - Happy Flow', () => {
+		it(
+			'should create a lockup linear stream for the claimant',
+			async () => {
+				const { campaign, campaignTreasury, getProof } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'Claim',
+					cliffDuration: 600,
+					totalDuration: 3600,
+				});
+
+				// Claimed streams are counted against the campaign's creator
+				const streamCounterIndex = await getStreamCounterIndex(program, alice.publicKey);
+				const [stream] = getStreamAddress(program, alice.publicKey, streamCounterIndex);
+
+				await claim(campaign, 0, 1_000, getProof(0));
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.sender.toBase58()).toBe(alice.publicKey.toBase58());
+				expect(streamData.baseStream.recipient.toBase58()).toBe(bob.publicKey.toBase58());
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(1_000);
+				// The claimant paid the rent of the stream, so they get it back when it is closed
+				expect(streamData.rentPayer.toBase58()).toBe(bob.publicKey.toBase58());
+				expect(streamData.cliffTime.toNumber() - streamData.baseStream.startTime.toNumber()).toBe(600);
+				expect(streamData.baseStream.endTime.toNumber() - streamData.baseStream.startTime.toNumber()).toBe(3600);
+
+				// The funds moved from the campaign's treasury to the stream's treasury
+				const campaignTreasuryBalance = await getTokenBalanceFor(campaignTreasury, banksClient);
+				expect(campaignTreasuryBalance.toNumber()).toBe(5_000);
+
+				// Bob holds the stream NFT
+				const nftTokenAccount = await getAccount(
+					provider.connection,
+					getAssociatedTokenAddressSync(streamData.nftMint, bob.publicKey, true, TOKEN_2022_PROGRAM_ID),
+					undefined,
+					TOKEN_2022_PROGRAM_ID
+				);
+				expect(nftTokenAccount.amount).toBe(BigInt(1));
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Campaign - Claim - Error Flow', () => {
+		it(
+			'should fail if the proof does not match the allocation',
+			async () => {
+				const { campaign, getProof } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'InvalidProof',
+				});
+
+				// Attempt to claim more than allocated
+				await expect(claim(campaign, 0, 2_000, getProof(0))).rejects.toThrow(/Merkle proof is invalid/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the allocation was already claimed',
+			async () => {
+				const { campaign, getProof } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'DoubleClaim',
+				});
+
+				await claim(campaign, 0, 1_000, getProof(0));
+
+				// The claim receipt already exists, so the second claim cannot go through
+				await expect(claim(campaign, 0, 1_000, getProof(0))).rejects.toThrow();
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the campaign has expired',
+			async () => {
+				const expiryTime = now() + 60;
+				const { campaign, getProof } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'Expired',
+					expiryTime,
+				});
+
+				timeTravelTo(expiryTime, banksClient, context);
+
+				await expect(claim(campaign, 0, 1_000, getProof(0))).rejects.toThrow(/Campaign has expired/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
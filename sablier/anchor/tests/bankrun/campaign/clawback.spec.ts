@@ -0,0 +1,108 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { createAssociatedTokenAccount } from 'spl-token-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup } from '../lockup-linear/setup';
+import { Allocation, createCampaign } from './utils';
+
+describe('Campaign - Clawback Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	let allocations: Allocation[];
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+
+		allocations = [{ claimant: bob.publicKey, amount: 1_000 }];
+	}, TIMEOUT);
+
+	const clawback = (creator: Keypair, campaign: PublicKey) => {
+		return program.methods
+			.clawbackFromCampaign()
+			.accounts({
+				campaign,
+				creator: creator.publicKey,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([creator])
+			.rpc();
+	};
+
+	describe('Campaign - Clawback - Happy Flow', () => {
+		it(
+			'should return the unclaimed funds to the creator after expiry',
+			async () => {
+				const expiryTime = now() + 60;
+				const { campaign, campaignTreasury } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'Clawback',
+					expiryTime,
+				});
+
+				const aliceBalanceBeforeClawback = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				timeTravelTo(expiryTime, banksClient, context);
+				await clawback(alice, campaign);
+
+				const campaignTreasuryBalance = await getTokenBalanceFor(campaignTreasury, banksClient);
+				expect(campaignTreasuryBalance.toNumber()).toBe(0);
+
+				const aliceBalanceAfterClawback = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterClawback.toNumber()).toBe(aliceBalanceBeforeClawback.toNumber() + 1_000);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Campaign - Clawback - Error Flow', () => {
+		it(
+			'should fail if the campaign has not expired yet',
+			async () => {
+				const { campaign } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'NotExpired',
+				});
+
+				await expect(clawback(alice, campaign)).rejects.toThrow(/Campaign has not expired yet/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			"should fail if the signer is not the campaign's creator",
+			async () => {
+				const expiryTime = now() + 60;
+				const { campaign } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'Unauthorized',
+					expiryTime,
+				});
+
+				// @ts-expect-error - Type error in spl-token-bankrun dependency
+				await createAssociatedTokenAccount(banksClient, bob, tokenMint, bob.publicKey);
+
+				timeTravelTo(expiryTime, banksClient, context);
+
+				await expect(clawback(bob, campaign)).rejects.toThrow(/Only the Campaign's Creator can claw back the Campaign's funds/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
@@ -0,0 +1,123 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient } from 'solana-bankrun';
+import { getTokenBalanceFor } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup } from '../lockup-linear/setup';
+import { Allocation, createCampaign, getMerkleTree } from './utils';
+
+describe('Campaign - Create Test', () => {
+	let banksClient: BanksClient;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	let allocations: Allocation[];
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, tokenMint, program } = await beforeAllSetup());
+
+		allocations = [
+			{ claimant: alice.publicKey, amount: 1_000 },
+			{ claimant: bob.publicKey, amount: 2_000 },
+		];
+	}, TIMEOUT);
+
+	describe('Campaign - Create - Happy Flow', () => {
+		it(
+			'should create a campaign and fund its treasury',
+			async () => {
+				const aliceBalanceBeforeCreation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				const expiryTime = now() + 86_400;
+				const { campaign, campaignTreasury } = await createCampaign(alice, tokenMint, program, allocations, {
+					campaignName: 'Create',
+					expiryTime,
+				});
+
+				const campaignData = await program.account.campaign.fetch(campaign);
+				expect(campaignData.name).toBe('Create');
+				expect(campaignData.creator.toBase58()).toBe(alice.publicKey.toBase58());
+				expect(campaignData.tokenMint.toBase58()).toBe(tokenMint.toBase58());
+				expect(Buffer.from(campaignData.merkleRoot)).toEqual(getMerkleTree(allocations).root);
+				expect(campaignData.expiryTime.toNumber()).toBe(expiryTime);
+
+				const campaignTreasuryBalance = await getTokenBalanceFor(campaignTreasury, banksClient);
+				expect(campaignTreasuryBalance.toNumber()).toBe(3_000);
+
+				const aliceBalanceAfterCreation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterCreation.toNumber()).toBe(aliceBalanceBeforeCreation.toNumber() - 3_000);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Campaign - Create - Error Flow', () => {
+		it(
+			'should fail if the expiry time is in the past',
+			async () => {
+				await expect(
+					createCampaign(alice, tokenMint, program, allocations, {
+						campaignName: 'PastExpiry',
+						expiryTime: now() - 1,
+					})
+				).rejects.toThrow(/Expiry time must be in the future/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the cliff is longer than the stream',
+			async () => {
+				await expect(
+					program.methods
+						.createCampaign('InvalidTemplate', Array(32).fill(0), new BN(1_000), new BN(now() + 86_400), {
+							cliffDuration: new BN(3601),
+							totalDuration: new BN(3600),
+							isCancelable: true,
+							isTransferable: true,
+						})
+						.accounts({
+							creator: alice.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Stream template durations are invalid/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream would end past the supported time range',
+			async () => {
+				await expect(
+					program.methods
+						.createCampaign('EndlessTemplate', Array(32).fill(0), new BN(1_000), new BN(now() + 86_400), {
+							cliffDuration: new BN(0),
+							totalDuration: new BN('9223372036854775807'),
+							isCancelable: true,
+							isTransferable: true,
+						})
+						.accounts({
+							creator: alice.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Stream template durations are invalid/);
+			},
+			TIMEOUT
+		);
+	});
+});
@@ -0,0 +1,120 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { keccak_256 } from '@noble/hashes/sha3';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { SEEDS, STREAM_NAME } from '../constants';
+import { now } from '../stream-utils';
+
+export interface Allocation {
+	claimant: PublicKey;
+	amount: number;
+}
+
+export interface CreateCampaignOptions {
+	campaignName?: string;
+	expiryTime?: number;
+	cliffDuration?: number;
+	totalDuration?: number;
+	isCancelable?: boolean;
+	isTransferable?: boolean;
+}
+
+const toLeBytes = (value: number) => new BN(value).toArrayLike(Buffer, 'le', 8);
+
+// Leaves are keccak(index || claimant || amount), matching the on-chain verification
+export const getLeaf = (index: number, { claimant, amount }: Allocation) => {
+	return Buffer.from(keccak_256(Buffer.concat([toLeBytes(index), claimant.toBuffer(), toLeBytes(amount)])));
+};
+
+const hashPair = (left: Buffer, right: Buffer) => {
+	const [first, second] = Buffer.compare(left, right) <= 0 ? [left, right] : [right, left];
+
+	return Buffer.from(keccak_256(Buffer.concat([first, second])));
+};
+
+// Builds a Merkle tree with sorted pair hashing, promoting the last node of odd-sized levels
+export const getMerkleTree = (allocations: Allocation[]) => {
+	const levels: Buffer[][] = [allocations.map((allocation, index) => getLeaf(index, allocation))];
+
+	while (levels[levels.length - 1].length > 1) {
+		const level = levels[levels.length - 1];
+		const nextLevel: Buffer[] = [];
+		for (let i = 0; i < level.length; i += 2) {
+			nextLevel.push(i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]);
+		}
+		levels.push(nextLevel);
+	}
+
+	const root = levels[levels.length - 1][0];
+
+	const getProof = (index: number) => {
+		const proof: number[][] = [];
+		for (const level of levels.slice(0, -1)) {
+			const siblingIndex = index % 2 === 0 ? index + 1 : index - 1;
+			if (siblingIndex < level.length) {
+				proof.push(Array.from(level[siblingIndex]));
+			}
+			index = Math.floor(index / 2);
+		}
+
+		return proof;
+	};
+
+	return { root, getProof };
+};
+
+export const getCampaign = (program: Program<Sablier>, creator: PublicKey, campaignName: string) => {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.CAMPAIGN.CAMPAIGN), creator.toBuffer(), Buffer.from(campaignName)],
+		program.programId
+	);
+};
+
+export const getCampaignTreasury = (program: Program<Sablier>, campaign: PublicKey) => {
+	return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.CAMPAIGN.TREASURY), campaign.toBuffer()], program.programId);
+};
+
+export const createCampaign = async (
+	creator: Keypair,
+	tokenMint: PublicKey,
+	program: Program<Sablier>,
+	allocations: Allocation[],
+	options: CreateCampaignOptions = {}
+) => {
+	// Assign defaults using destructuring
+	const {
+		campaignName = STREAM_NAME,
+		expiryTime = now() + 86_400, // Expire in 1 day
+		cliffDuration = 1800, // Cliff after 30 minutes
+		totalDuration = 3600, // End after 1 hour
+		isCancelable = true,
+		isTransferable = true,
+	} = options;
+
+	const { root, getProof } = getMerkleTree(allocations);
+	const amount = allocations.reduce((total, allocation) => total + allocation.amount, 0);
+
+	const createCampaignTx = await program.methods
+		.createCampaign(campaignName, Array.from(root), new BN(amount), new BN(expiryTime), {
+			cliffDuration: new BN(cliffDuration),
+			totalDuration: new BN(totalDuration),
+			isCancelable,
+			isTransferable,
+		})
+		.accounts({
+			creator: creator.publicKey,
+			tokenMint,
+			tokenProgram: TOKEN_PROGRAM_ID,
+		})
+		.signers([creator])
+		.rpc();
+
+	// Ensure the transaction was successful
+	expect(createCampaignTx).toBeDefined();
+
+	const [campaign] = getCampaign(program, creator.publicKey, campaignName);
+	const [campaignTreasury] = getCampaignTreasury(program, campaign);
+
+	return { campaign, campaignTreasury, getProof };
+};
@@ -0,0 +1,183 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient } from 'solana-bankrun';
+import { getTokenBalanceFor } from '../bankrun-utils';
+import { SEEDS, TIMEOUT } from '../constants';
+import { createCampaign } from '../campaign/utils';
+import { beforeAllSetup, createStream } from '../lockup-linear/setup';
+import { getStreamAddress, getStreamCounterIndex } from '../lockup-linear/utils';
+
+describe('Config - Protocol Fee Test', () => {
+	let banksClient: BanksClient;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+	let teamKeypair: Keypair;
+
+	let tokenMint: PublicKey;
+	let feeVault: PublicKey;
+
+	const setProtocolFee = async (protocolFeeBps: number, authority: Keypair = teamKeypair) => {
+		await program.methods
+			.setProtocolFee(teamKeypair.publicKey, protocolFeeBps)
+			.accounts({
+				authority: authority.publicKey,
+			})
+			.signers([authority])
+			.rpc();
+	};
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, teamKeypair, tokenMint, program } = await beforeAllSetup());
+
+		[feeVault] = PublicKey.findProgramAddressSync(
+			[Buffer.from(SEEDS.CONFIG.FEE_VAULT), tokenMint.toBuffer()],
+			program.programId
+		);
+	}, TIMEOUT);
+
+	describe('Config - Protocol Fee - Happy Flow', () => {
+		it(
+			'should initialize the config without a protocol fee',
+			async () => {
+				const [config] = PublicKey.findProgramAddressSync([Buffer.from(SEEDS.CONFIG.CONFIG)], program.programId);
+				const configData = await program.account.config.fetch(config);
+
+				expect(configData.authority.toBase58()).toBe(teamKeypair.publicKey.toBase58());
+				expect(configData.feeRecipient.toBase58()).toBe(teamKeypair.publicKey.toBase58());
+				expect(configData.protocolFeeBps).toBe(0);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should split the deposit between the treasury and the fee vault',
+			async () => {
+				await setProtocolFee(100); // 1%
+
+				const aliceBalanceBefore = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				const [stream] = await createStream(alice, bob, tokenMint, program, { amount: 1_000 });
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(990);
+				expect(streamData.baseStream.amounts.protocolFee.toNumber()).toBe(10);
+
+				const feeVaultBalance = await getTokenBalanceFor(feeVault, banksClient);
+				expect(feeVaultBalance.toNumber()).toBe(10);
+
+				// The sender pays the full amount, fee included
+				const aliceBalanceAfter = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfter.toNumber()).toBe(aliceBalanceBefore.toNumber() - 1_000);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should charge the protocol fee on campaign claims',
+			async () => {
+				const { campaign, getProof } = await createCampaign(
+					alice,
+					tokenMint,
+					program,
+					[{ claimant: bob.publicKey, amount: 1_000 }],
+					{ campaignName: 'ProtocolFee' }
+				);
+				const feeVaultBalanceBefore = await getTokenBalanceFor(feeVault, banksClient);
+
+				const streamCounterIndex = await getStreamCounterIndex(program, alice.publicKey);
+				const [stream] = getStreamAddress(program, alice.publicKey, streamCounterIndex);
+
+				await program.methods
+					.claimFromCampaign(new BN(0), new BN(1_000), getProof(0))
+					.accounts({
+						campaign,
+						claimant: bob.publicKey,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+					})
+					.signers([bob])
+					.rpc();
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(990);
+				expect(streamData.baseStream.amounts.protocolFee.toNumber()).toBe(10);
+
+				const feeVaultBalanceAfter = await getTokenBalanceFor(feeVault, banksClient);
+				expect(feeVaultBalanceAfter.toNumber()).toBe(feeVaultBalanceBefore.toNumber() + 10);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should collect the accumulated fees to the fee recipient',
+			async () => {
+				const feeVaultBalanceBefore = await getTokenBalanceFor(feeVault, banksClient);
+
+				await program.methods
+					.collectProtocolFees()
+					.accounts({
+						authority: teamKeypair.publicKey,
+						feeRecipient: teamKeypair.publicKey,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+					})
+					.signers([teamKeypair])
+					.rpc();
+
+				const feeRecipientTokenAccount = getAssociatedTokenAddressSync(tokenMint, teamKeypair.publicKey);
+				const feeRecipientBalance = await getTokenBalanceFor(feeRecipientTokenAccount, banksClient);
+				expect(feeRecipientBalance.toNumber()).toBe(feeVaultBalanceBefore.toNumber());
+
+				const feeVaultBalanceAfter = await getTokenBalanceFor(feeVault, banksClient);
+				expect(feeVaultBalanceAfter.toNumber()).toBe(0);
+
+				await setProtocolFee(0);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Config - Protocol Fee - Sad Flow', () => {
+		it(
+			'should fail to set a protocol fee above the maximum',
+			async () => {
+				await expect(setProtocolFee(1_001)).rejects.toThrow(/Protocol fee exceeds the maximum allowed/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if someone other than the authority sets the protocol fee',
+			async () => {
+				await expect(setProtocolFee(100, alice)).rejects.toThrow(
+					/Only the Config's Authority can update the Config or collect fees/
+				);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail to collect fees when the fee vault is empty',
+			async () => {
+				await expect(
+					program.methods
+						.collectProtocolFees()
+						.accounts({
+							authority: teamKeypair.publicKey,
+							feeRecipient: teamKeypair.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.signers([teamKeypair])
+						.rpc()
+				).rejects.toThrow(/There are no protocol fees to collect/);
+			},
+			TIMEOUT
+		);
+	});
+});
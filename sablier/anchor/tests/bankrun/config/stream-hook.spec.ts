@@ -0,0 +1,172 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { SEEDS, TIMEOUT } from '../constants';
+import { beforeAllSetup, createStream } from '../lockup-linear/setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from '../lockup-linear/utils';
+import { now } from '../stream-utils';
+
+describe('Config - Stream Hook Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+	let bob: Keypair;
+	let teamKeypair: Keypair;
+
+	let tokenMint: PublicKey;
+
+	// Any executable program works here, since the hook is never reached in these tests
+	const hookProgramId = TOKEN_PROGRAM_ID;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, teamKeypair, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const getStreamHookAddress = (account: PublicKey) => {
+		return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.CONFIG.STREAM_HOOK), account.toBuffer()], program.programId);
+	};
+
+	const registerStreamHook = (account: PublicKey, authority: Keypair = teamKeypair) => {
+		return program.methods
+			.registerStreamHook(account, hookProgramId)
+			.accounts({ authority: authority.publicKey })
+			.signers([authority])
+			.rpc();
+	};
+
+	const unregisterStreamHook = (account: PublicKey) => {
+		return program.methods
+			.unregisterStreamHook()
+			.accounts({ authority: teamKeypair.publicKey, streamHook: getStreamHookAddress(account)[0] })
+			.signers([teamKeypair])
+			.rpc();
+	};
+
+	const withdrawMax = async (stream: PublicKey, treasuryTokenAccount: PublicKey) => {
+		return program.methods
+			.withdrawMaxFromLockupLinearStream()
+			.accounts({
+				recipient: bob.publicKey,
+				recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+				signer: bob.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([bob])
+			.rpc();
+	};
+
+	const withdrawTo = async (stream: PublicKey, treasuryTokenAccount: PublicKey, destinationTokenAccount: PublicKey) => {
+		return program.methods
+			.withdrawFromLockupLinearStreamTo(new BN(1))
+			.accounts({
+				destinationTokenAccount,
+				recipient: bob.publicKey,
+				recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+				signer: bob.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([bob])
+			.rpc();
+	};
+
+	describe('Config - Stream Hook - Happy Flow', () => {
+		it(
+			'should register and unregister a hook for an account',
+			async () => {
+				const account = Keypair.generate().publicKey;
+				const [streamHook] = getStreamHookAddress(account);
+
+				await registerStreamHook(account);
+
+				const streamHookData = await program.account.streamHook.fetch(streamHook);
+				expect(streamHookData.account.toBase58()).toBe(account.toBase58());
+				expect(streamHookData.programId.toBase58()).toBe(hookProgramId.toBase58());
+
+				await unregisterStreamHook(account);
+
+				expect(await banksClient.getAccount(streamHook)).toBeNull();
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Config - Stream Hook - Error Flow', () => {
+		it(
+			'should fail if the signer is not the config authority',
+			async () => {
+				await expect(registerStreamHook(Keypair.generate().publicKey, alice)).rejects.toThrow(
+					/Only the Config's Authority can update the Config or collect fees/
+				);
+			},
+			TIMEOUT
+		);
+
+		it(
+			"should not withdraw without the recipient's hook program",
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				await registerStreamHook(bob.publicKey);
+				await timeTravelTo(startTime + 50, banksClient, context);
+
+				await expect(withdrawMax(stream, treasuryTokenAccount)).rejects.toThrow(
+					/Hook program account does not match the registered Stream Hook/
+				);
+
+				// The withdrawal goes through again once the hook is unregistered
+				await unregisterStreamHook(bob.publicKey);
+				await withdrawMax(stream, treasuryTokenAccount);
+			},
+			TIMEOUT
+		);
+
+		it(
+			"should not withdraw to another token account without the recipient's hook program",
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				await registerStreamHook(bob.publicKey);
+				await timeTravelTo(startTime + 50, banksClient, context);
+
+				await expect(withdrawTo(stream, treasuryTokenAccount, aliceTokenAccount)).rejects.toThrow(
+					/Hook program account does not match the registered Stream Hook/
+				);
+
+				await unregisterStreamHook(bob.publicKey);
+				await withdrawTo(stream, treasuryTokenAccount, aliceTokenAccount);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
@@ -2,9 +2,38 @@ export const STREAM_NAME = 'TestStream';
 export const TIMEOUT = 30_000;
 
 export const SEEDS = {
+	CONFIG: {
+		CONFIG: 'Config',
+		FEE_VAULT: 'ProtocolFeeVault',
+		STREAM_HOOK: 'StreamHook',
+	},
 	LOCKUP_LINEAR: {
 		STREAM: 'LockupLinearStream',
 		TREASURY: 'LockupLinearTreasury',
 		COUNTER: 'LockupLinearStreamCounter',
+		NFT_MINT: 'LockupLinearNftMint',
+	},
+	LOCKUP_DYNAMIC: {
+		STREAM: 'LockupDynamicStream',
+		TREASURY: 'LockupDynamicTreasury',
+		COUNTER: 'LockupDynamicStreamCounter',
+	},
+	CAMPAIGN: {
+		CAMPAIGN: 'Campaign',
+		TREASURY: 'CampaignTreasury',
+		CLAIM_RECEIPT: 'CampaignClaimReceipt',
+	},
+	LOCKUP_TRANCHED: {
+		STREAM: 'LockupTranchedStream',
+		TREASURY: 'LockupTranchedTreasury',
+		COUNTER: 'LockupTranchedStreamCounter',
+	},
+	FLOW: {
+		STREAM: 'FlowStream',
+		TREASURY: 'FlowTreasury',
+		COUNTER: 'FlowStreamCounter',
 	},
 };
+
+// Fixed-point scale of segment exponents (1e18 represents an exponent of 1)
+export const SEGMENT_EXPONENT_SCALE = '1000000000000000000';
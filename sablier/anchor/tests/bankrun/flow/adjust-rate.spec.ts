@@ -0,0 +1,79 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Adjust Rate Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const adjustRate = (signer: Keypair, stream: PublicKey, ratePerSecond: number) => {
+		return program.methods
+			.adjustFlowStreamRate(new BN(ratePerSecond))
+			.accounts({ sender: signer.publicKey, stream })
+			.signers([signer])
+			.rpc();
+	};
+
+	describe('Flow Stream - Adjust Rate - Happy Flow', () => {
+		it(
+			'should keep the debt accrued at the old rate',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream } = await createStream(alice, bob, tokenMint, program, { ratePerSecond: 10 });
+
+				await timeTravelTo(createTime + 20, banksClient, context);
+				await adjustRate(alice, stream, 3);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.ratePerSecond.toNumber()).toBe(3);
+				expect(streamData.snapshotDebt.toNumber()).toBe(200);
+				expect(streamData.snapshotTime.toNumber()).toBe(createTime + 20);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Adjust Rate - Error Flow', () => {
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+
+				await expect(adjustRate(bob, stream, 3)).rejects.toThrow(/Only the Flow Stream's Sender can manage the Flow Stream/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the rate does not change',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program, { ratePerSecond: 10 });
+
+				await expect(adjustRate(alice, stream, 10)).rejects.toThrow(/Rate per second must be greater than 0/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
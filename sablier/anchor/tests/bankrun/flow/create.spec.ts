@@ -0,0 +1,94 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { STREAM_NAME, TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getStreamCounterIndex } from './utils';
+
+describe('Flow Stream - Create Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Flow Stream - Create - Happy Flow', () => {
+		it(
+			'should create an unfunded stream that starts accruing right away',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const streamCounterIndex = await getStreamCounterIndex(program, alice.publicKey);
+				const { stream } = await createStream(alice, bob, tokenMint, program, { ratePerSecond: 5, depositAmount: 0 });
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.index.toNumber()).toBe(streamCounterIndex);
+				expect(streamData.name).toBe(STREAM_NAME);
+				expect(streamData.sender.toBase58()).toBe(alice.publicKey.toBase58());
+				expect(streamData.recipient.toBase58()).toBe(bob.publicKey.toBase58());
+				expect(streamData.tokenMint.toBase58()).toBe(tokenMint.toBase58());
+				expect(streamData.ratePerSecond.toNumber()).toBe(5);
+				expect(streamData.snapshotTime.toNumber()).toBe(createTime);
+				expect(streamData.snapshotDebt.toNumber()).toBe(0);
+				expect(streamData.balance.toNumber()).toBe(0);
+				expect(streamData.isVoided).toBe(false);
+
+				expect(await getStreamCounterIndex(program, alice.publicKey)).toBe(streamCounterIndex + 1);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Create - Error Flow', () => {
+		it(
+			'should fail if the rate per second is 0',
+			async () => {
+				await expect(
+					program.methods
+						.createFlowStream(STREAM_NAME, bob.publicKey, new BN(0))
+						.accounts({
+							sender: alice.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Rate per second must be greater than 0/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the rate per second exceeds the maximum',
+			async () => {
+				// The debt accrued at the maximum rate fits in a u64 for 100 years
+				const maxRatePerSecond = new BN('18446744073709551615').div(new BN(100 * 365 * 24 * 60 * 60));
+
+				await expect(
+					program.methods
+						.createFlowStream(STREAM_NAME, bob.publicKey, maxRatePerSecond.addn(1))
+						.accounts({
+							sender: alice.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Rate per second exceeds the maximum allowed/);
+			},
+			TIMEOUT
+		);
+	});
+});
@@ -0,0 +1,85 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient } from 'solana-bankrun';
+import { getTokenBalanceFor } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Deposit Test', () => {
+	let banksClient: BanksClient;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const deposit = (stream: PublicKey, treasuryTokenAccount: PublicKey, amount: number) => {
+		return program.methods
+			.depositIntoFlowStream(new BN(amount))
+			.accounts({
+				depositor: alice.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([alice])
+			.rpc();
+	};
+
+	describe('Flow Stream - Deposit - Happy Flow', () => {
+		it(
+			'should add the deposit to the balance of the stream',
+			async () => {
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program, { depositAmount: 0 });
+				const aliceBalanceBeforeDeposit = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				await deposit(stream, treasuryTokenAccount, 700);
+				await deposit(stream, treasuryTokenAccount, 300);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.balance.toNumber()).toBe(1_000);
+
+				const treasuryBalance = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+				expect(treasuryBalance.toNumber()).toBe(1_000);
+
+				const aliceBalanceAfterDeposit = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterDeposit.toNumber()).toBe(aliceBalanceBeforeDeposit.toNumber() - 1_000);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Deposit - Error Flow', () => {
+		it(
+			'should fail if the amount is 0',
+			async () => {
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program);
+
+				await expect(deposit(stream, treasuryTokenAccount, 0)).rejects.toThrow(/Amount must be greater than 0/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is voided',
+			async () => {
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program);
+
+				await program.methods.voidFlowStream().accounts({ signer: alice.publicKey, stream }).signers([alice]).rpc();
+
+				await expect(deposit(stream, treasuryTokenAccount, 100)).rejects.toThrow(/Flow Stream is already voided/);
+			},
+			TIMEOUT
+		);
+	});
+});
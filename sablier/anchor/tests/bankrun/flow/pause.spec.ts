@@ -0,0 +1,75 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Pause Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const pause = (signer: Keypair, stream: PublicKey) => {
+		return program.methods.pauseFlowStream().accounts({ sender: signer.publicKey, stream }).signers([signer]).rpc();
+	};
+
+	describe('Flow Stream - Pause - Happy Flow', () => {
+		it(
+			'should stop accruing debt',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream } = await createStream(alice, bob, tokenMint, program, { ratePerSecond: 10 });
+
+				await timeTravelTo(createTime + 30, banksClient, context);
+				await pause(alice, stream);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.ratePerSecond.toNumber()).toBe(0);
+				expect(streamData.snapshotDebt.toNumber()).toBe(300);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Pause - Error Flow', () => {
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+
+				await expect(pause(bob, stream)).rejects.toThrow(/Only the Flow Stream's Sender can manage the Flow Stream/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is already paused',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+				await pause(alice, stream);
+
+				await expect(pause(alice, stream)).rejects.toThrow(/Flow Stream is already paused/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
@@ -0,0 +1,105 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Refund Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const refund = (signer: Keypair, stream: PublicKey, treasuryTokenAccount: PublicKey, amount: number) => {
+		return program.methods
+			.refundFromFlowStream(new BN(amount))
+			.accounts({
+				sender: signer.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([signer])
+			.rpc();
+	};
+
+	describe('Flow Stream - Refund - Happy Flow', () => {
+		it(
+			'should refund the balance that is not owed to the recipient',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program, {
+					ratePerSecond: 10,
+					depositAmount: 1_000,
+				});
+
+				// 200 is owed to Bob, so 800 can be refunded
+				await timeTravelTo(createTime + 20, banksClient, context);
+				const aliceBalanceBeforeRefund = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				await refund(alice, stream, treasuryTokenAccount, 800);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.balance.toNumber()).toBe(200);
+
+				const aliceBalanceAfterRefund = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterRefund.toNumber()).toBe(aliceBalanceBeforeRefund.toNumber() + 800);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Refund - Error Flow', () => {
+		it(
+			'should fail if the amount exceeds the refundable balance',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program, {
+					ratePerSecond: 10,
+					depositAmount: 1_000,
+				});
+
+				await timeTravelTo(createTime + 20, banksClient, context);
+
+				await expect(refund(alice, stream, treasuryTokenAccount, 801)).rejects.toThrow(
+					/Refund amount exceeds the refundable balance/
+				);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program);
+
+				await expect(refund(bob, stream, treasuryTokenAccount, 100)).rejects.toThrow();
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
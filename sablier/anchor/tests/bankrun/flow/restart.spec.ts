@@ -0,0 +1,87 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Restart Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const pause = (stream: PublicKey) => {
+		return program.methods.pauseFlowStream().accounts({ sender: alice.publicKey, stream }).signers([alice]).rpc();
+	};
+
+	const restart = (signer: Keypair, stream: PublicKey, ratePerSecond: number) => {
+		return program.methods
+			.restartFlowStream(new BN(ratePerSecond))
+			.accounts({ sender: signer.publicKey, stream })
+			.signers([signer])
+			.rpc();
+	};
+
+	describe('Flow Stream - Restart - Happy Flow', () => {
+		it(
+			'should not accrue debt for the paused period',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream } = await createStream(alice, bob, tokenMint, program, { ratePerSecond: 10 });
+
+				await timeTravelTo(createTime + 10, banksClient, context);
+				await pause(stream);
+
+				await timeTravelTo(createTime + 100, banksClient, context);
+				await restart(alice, stream, 4);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.ratePerSecond.toNumber()).toBe(4);
+				expect(streamData.snapshotDebt.toNumber()).toBe(100);
+				expect(streamData.snapshotTime.toNumber()).toBe(createTime + 100);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Restart - Error Flow', () => {
+		it(
+			'should fail if the stream is not paused',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+
+				await expect(restart(alice, stream, 4)).rejects.toThrow(/Flow Stream is not paused/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+				await pause(stream);
+
+				await expect(restart(bob, stream, 4)).rejects.toThrow(/Only the Flow Stream's Sender can manage the Flow Stream/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
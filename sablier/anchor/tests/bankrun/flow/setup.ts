@@ -0,0 +1,66 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { SEEDS, STREAM_NAME } from '../constants';
+import { environmentSetup } from '../stream-utils';
+import { getStreamAddress, getStreamCounterIndex, getTreasuryTokenAccount } from './utils';
+
+export interface CreateFlowStreamOptions {
+	streamName?: string;
+	ratePerSecond?: number;
+	depositAmount?: number;
+}
+
+export const beforeAllSetup = async () => {
+	return environmentSetup(SEEDS.FLOW.COUNTER);
+};
+
+// Creates a flow stream and, unless the deposit amount is 0, funds it from the sender's balance
+export const createStream = async (
+	sender: Keypair,
+	recipient: Keypair,
+	tokenMint: PublicKey,
+	program: Program<Sablier>,
+	options: CreateFlowStreamOptions = {}
+) => {
+	const {
+		streamName = STREAM_NAME,
+		ratePerSecond = 10, // Default rate: 10 tokens per second
+		depositAmount = 1_000, // Default deposit: 1K tokens
+	} = options;
+
+	// Get the stream counter index for the stream to be created
+	const streamCounterIndex = await getStreamCounterIndex(program, sender.publicKey);
+
+	const createStreamTx = await program.methods
+		.createFlowStream(streamName, recipient.publicKey, new BN(ratePerSecond))
+		.accounts({
+			sender: sender.publicKey,
+			tokenMint,
+			tokenProgram: TOKEN_PROGRAM_ID,
+		})
+		.signers([sender])
+		.rpc();
+
+	expect(createStreamTx).toBeDefined();
+
+	const [stream] = getStreamAddress(program, sender.publicKey, streamCounterIndex);
+	const [treasuryTokenAccount] = getTreasuryTokenAccount(program, stream);
+
+	if (depositAmount > 0) {
+		await program.methods
+			.depositIntoFlowStream(new BN(depositAmount))
+			.accounts({
+				depositor: sender.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([sender])
+			.rpc();
+	}
+
+	return { stream, treasuryTokenAccount };
+};
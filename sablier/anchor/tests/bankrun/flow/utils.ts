@@ -0,0 +1,20 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { PublicKey } from '@solana/web3.js';
+import { SEEDS } from '../constants';
+import { getStreamCounterIndexWithSeed } from '../stream-utils';
+
+export const getStreamCounterIndex = async (program: Program<Sablier>, sender: PublicKey) => {
+	return getStreamCounterIndexWithSeed(program, SEEDS.FLOW.COUNTER, sender);
+};
+
+export const getStreamAddress = (program: Program<Sablier>, sender: PublicKey, streamCounterIndex: number) => {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.FLOW.STREAM), sender.toBuffer(), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
+		program.programId
+	);
+};
+
+export const getTreasuryTokenAccount = (program: Program<Sablier>, stream: PublicKey) => {
+	return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.FLOW.TREASURY), stream.toBuffer()], program.programId);
+};
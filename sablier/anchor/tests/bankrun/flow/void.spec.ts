@@ -0,0 +1,78 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Void Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const voidStream = (signer: Keypair, stream: PublicKey) => {
+		return program.methods.voidFlowStream().accounts({ signer: signer.publicKey, stream }).signers([signer]).rpc();
+	};
+
+	describe('Flow Stream - Void - Happy Flow', () => {
+		it(
+			'should forgive the uncovered debt when voided by the recipient',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream } = await createStream(alice, bob, tokenMint, program, { ratePerSecond: 10, depositAmount: 100 });
+
+				// The debt is 500 by now, but only 100 of it is covered
+				await timeTravelTo(createTime + 50, banksClient, context);
+				await voidStream(bob, stream);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.isVoided).toBe(true);
+				expect(streamData.ratePerSecond.toNumber()).toBe(0);
+				expect(streamData.snapshotDebt.toNumber()).toBe(100);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Void - Error Flow', () => {
+		it(
+			'should fail if the signer is neither the sender nor the recipient',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+				const eve = Keypair.generate();
+
+				await expect(voidStream(eve, stream)).rejects.toThrow();
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is already voided',
+			async () => {
+				const { stream } = await createStream(alice, bob, tokenMint, program);
+				await voidStream(alice, stream);
+
+				await expect(voidStream(alice, stream)).rejects.toThrow(/Flow Stream is already voided/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
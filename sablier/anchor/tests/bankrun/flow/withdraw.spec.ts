@@ -0,0 +1,92 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Flow Stream - Withdraw Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const withdraw = (stream: PublicKey, treasuryTokenAccount: PublicKey, amount: number) => {
+		return program.methods
+			.withdrawFromFlowStream(new BN(amount))
+			.accounts({
+				recipient: bob.publicKey,
+				signer: bob.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([bob])
+			.rpc();
+	};
+
+	describe('Flow Stream - Withdraw - Happy Flow', () => {
+		it(
+			'should withdraw part of the covered debt',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program, {
+					ratePerSecond: 10,
+					depositAmount: 1_000,
+				});
+
+				await timeTravelTo(createTime + 40, banksClient, context);
+				await withdraw(stream, treasuryTokenAccount, 300);
+
+				const streamData = await program.account.flowStream.fetch(stream);
+				expect(streamData.balance.toNumber()).toBe(700);
+				expect(streamData.snapshotDebt.toNumber()).toBe(100);
+
+				const bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey);
+				const bobBalance = await getTokenBalanceFor(bobTokenAccount, banksClient);
+				expect(bobBalance.toNumber()).toBeGreaterThanOrEqual(300);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Flow Stream - Withdraw - Error Flow', () => {
+		it(
+			'should fail if the amount exceeds the covered debt',
+			async () => {
+				const createTime = now();
+				await timeTravelTo(createTime, banksClient, context);
+
+				const { stream, treasuryTokenAccount } = await createStream(alice, bob, tokenMint, program, {
+					ratePerSecond: 10,
+					depositAmount: 100,
+				});
+
+				// The debt is 500 by now, but only 100 of it is covered
+				await timeTravelTo(createTime + 50, banksClient, context);
+
+				await expect(withdraw(stream, treasuryTokenAccount, 101)).rejects.toThrow(/Withdrawal amount exceeds the covered debt/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
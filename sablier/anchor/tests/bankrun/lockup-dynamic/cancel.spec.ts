@@ -0,0 +1,136 @@
+import { Program } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+
+import { Sablier } from '@project/anchor';
+import { getTokenBalanceFor, timeTravelFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Dynamic Stream - Cancel Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Dynamic Stream - Cancel - Happy Flow', () => {
+		it(
+			'should refund the amount that has not been streamed yet',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					segments: [
+						{ amount: 500, milestone: startTime + 50 },
+						{ amount: 500, milestone: startTime + 100 },
+					],
+				});
+
+				const aliceBalanceAfterCreation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				// Halfway through the first segment
+				timeTravelTo(startTime + 25, banksClient, context);
+
+				await program.methods
+					.cancelLockupDynamicStream()
+					.accounts({
+						sender: alice.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([alice])
+					.rpc();
+
+				const treasuryBalanceAfterCancelation = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+				expect(treasuryBalanceAfterCancelation.toNumber()).toBe(250);
+
+				const aliceBalanceAfterCancelation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterCancelation.toNumber()).toBe(aliceBalanceAfterCreation.toNumber() + 750);
+
+				const streamData = await program.account.lockupDynamicStream.fetch(stream);
+				expect(streamData.baseStream.isCanceled).toBe(true);
+				expect(streamData.baseStream.isCancelable).toBe(false);
+				expect(streamData.baseStream.amounts.refunded.toNumber()).toEqual(750);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Dynamic Stream - Cancel - Error Flow', () => {
+		it(
+			"should fail if the sender is not the stream's creator",
+			async () => {
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(
+					program.methods
+						.cancelLockupDynamicStream()
+						.accounts({
+							sender: bob.publicKey,
+							stream,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+							treasuryTokenAccount,
+						})
+						.signers([bob])
+						.rpc()
+				).rejects.toThrow(/Only the Stream's Creator can cancel the Stream/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream has already ended',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					segments: [{ amount: 1_000, milestone: startTime + 5 }], // Ends quickly
+				});
+
+				// Wait for the stream to end
+				timeTravelFor(3600, banksClient, context);
+
+				await expect(
+					program.methods
+						.cancelLockupDynamicStream()
+						.accounts({
+							sender: alice.publicKey,
+							stream,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+							treasuryTokenAccount,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Stream is not cancelable after the end time has passed/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
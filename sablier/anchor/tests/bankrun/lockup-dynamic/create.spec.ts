@@ -0,0 +1,142 @@
+import { Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+
+import { Sablier } from '@project/anchor';
+import { STREAM_NAME, TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Lockup Dynamic Stream - Create Test', () => {
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, bob, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Dynamic Stream - Create - Happy Flow', () => {
+		it(
+			'should create a lockup dynamic stream',
+			async () => {
+				// Create a lockup dynamic stream
+				const startTime = now() + 60;
+				const options = {
+					streamName: STREAM_NAME,
+					startTime,
+					segments: [
+						{ amount: 250, milestone: startTime + 1800 },
+						{ amount: 750, milestone: startTime + 3600 },
+					],
+					isCancelable: true,
+					isTransferable: true,
+				};
+
+				const [stream] = await createStream(alice, bob, tokenMint, program, options);
+
+				// Verify that all fields in the stream are properly populated
+				const streamData = await program.account.lockupDynamicStream.fetch(stream);
+
+				expect(streamData.baseStream.index.toNumber()).toBe(0);
+
+				expect(streamData.baseStream.sender.toBase58()).toBe(alice.publicKey.toBase58());
+				expect(streamData.baseStream.recipient.toBase58()).toBe(bob.publicKey.toBase58());
+
+				expect(streamData.baseStream.tokenMint.toBase58()).toBe(tokenMint.toBase58());
+
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(1_000);
+				expect(streamData.baseStream.amounts.refunded.toNumber()).toBe(0);
+				expect(streamData.baseStream.amounts.withdrawn.toNumber()).toBe(0);
+
+				// The stream ends at the last segment's milestone
+				expect(streamData.baseStream.startTime.toNumber()).toBe(options.startTime);
+				expect(streamData.baseStream.endTime.toNumber()).toBe(startTime + 3600);
+
+				expect(streamData.segments.length).toBe(2);
+				expect(streamData.segments[0].amount.toNumber()).toBe(250);
+				expect(streamData.segments[1].milestone.toNumber()).toBe(startTime + 3600);
+
+				expect(streamData.baseStream.isCancelable).toBe(options.isCancelable);
+				expect(streamData.baseStream.isCanceled).toBe(false); // Streams are not canceled by default
+				expect(streamData.baseStream.isTransferable).toBe(options.isTransferable);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Dynamic Stream - Create - Error Flow', () => {
+		it(
+			'should fail if start time is in the past',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						startTime: 0, // Start time in the past
+						segments: [{ amount: 1_000, milestone: now() + 100 }],
+					})
+				).rejects.toThrow(/Start time must not be in the past/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if there are no segments',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						segments: [],
+						amount: 1_000,
+					})
+				).rejects.toThrow(/Segment count must be between 1 and the maximum allowed/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the segment milestones are not strictly increasing',
+			async () => {
+				const startTime = now() + 60;
+
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						startTime,
+						segments: [
+							{ amount: 500, milestone: startTime + 200 },
+							{ amount: 500, milestone: startTime + 100 }, // Milestone before the previous one
+						],
+					})
+				).rejects.toThrow(/Segment milestones must be strictly increasing and after the start time/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the segment amounts do not add up to the deposited amount',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						amount: 1_001,
+					})
+				).rejects.toThrow(/Segment amounts must add up to the deposited amount/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if amount is zero',
+			async () => {
+				const startTime = now() + 60;
+
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						startTime,
+						segments: [{ amount: 0, milestone: startTime + 100 }],
+					})
+				).rejects.toThrow(/Amount must be greater than 0/);
+			},
+			TIMEOUT
+		);
+	});
+});
@@ -0,0 +1,83 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { SEGMENT_EXPONENT_SCALE, SEEDS, STREAM_NAME } from '../constants';
+import { getTeamLockupStreamAddress, teamLockupSetup, TeamLockupFamily } from '../lockup-utils';
+import { now } from '../stream-utils';
+import { getStreamCounterIndex } from './utils';
+
+export interface SegmentOptions {
+	amount: number;
+	exponent?: BN;
+	milestone: number;
+}
+
+export interface CreateLockupDynamicStreamOptions {
+	streamName?: string;
+	startTime?: number;
+	segments?: SegmentOptions[];
+	amount?: number;
+	isCancelable?: boolean;
+	isTransferable?: boolean;
+}
+
+export const LOCKUP_DYNAMIC: TeamLockupFamily = {
+	seeds: SEEDS.LOCKUP_DYNAMIC,
+	initializeStreamCounter: 'initializeLockupDynamicStreamCounter',
+};
+
+export const beforeAllSetup = () => teamLockupSetup(LOCKUP_DYNAMIC);
+
+export const createStream = async (
+	sender: Keypair,
+	recipient: Keypair,
+	tokenMint: PublicKey,
+	program: Program<Sablier>,
+	options: CreateLockupDynamicStreamOptions = {}
+) => {
+	// Assign defaults using destructuring
+	const {
+		streamName = STREAM_NAME,
+		startTime = now() + 60, // Start in 1 minute
+		segments = [
+			{ amount: 500, milestone: startTime + 1800 }, // Half in 30 minutes
+			{ amount: 500, milestone: startTime + 3600 }, // The rest in 1 hour
+		],
+		isCancelable = true,
+		isTransferable = true,
+	} = options;
+	const amount = options.amount ?? segments.reduce((total, segment) => total + segment.amount, 0);
+
+	// Get the stream counter index for the stream to be created
+	const streamCounterIndex = await getStreamCounterIndex(program);
+
+	// Send the createStream transaction
+	const createStreamTx = await program.methods
+		.createLockupDynamicStream(
+			streamName,
+			recipient.publicKey,
+			new BN(amount),
+			new BN(startTime),
+			segments.map(({ amount, exponent = new BN(SEGMENT_EXPONENT_SCALE), milestone }) => ({
+				amount: new BN(amount),
+				exponent,
+				milestone: new BN(milestone),
+			})),
+			isCancelable,
+			isTransferable
+		)
+		.accounts({
+			sender: sender.publicKey,
+			tokenMint,
+			tokenProgram: TOKEN_PROGRAM_ID,
+		})
+		.signers([sender])
+		.rpc();
+
+	// Ensure the transaction was successful
+	expect(createStreamTx).toBeDefined();
+
+	// Derive the PDA for the newly created stream
+	return getTeamLockupStreamAddress(LOCKUP_DYNAMIC, program, streamCounterIndex);
+};
@@ -0,0 +1,16 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { PublicKey } from '@solana/web3.js';
+import { SEEDS } from '../constants';
+import { getStreamCounterIndexWithSeed, getTreasuryTokenAccountWithSeeds } from '../stream-utils';
+
+export const getStreamCounterIndex = async (program: Program<Sablier>) => {
+	return getStreamCounterIndexWithSeed(program, SEEDS.LOCKUP_DYNAMIC.COUNTER);
+};
+
+//! Must be used before creating the stream since it gets the Stream Counter Index
+//! which is used TO CREATE the stream
+//! Calling this after creating the stream will result in it using a future counter index
+export const getTreasuryTokenAccount = async (tokenMint: PublicKey, program: Program<Sablier>) => {
+	return getTreasuryTokenAccountWithSeeds(tokenMint, program, SEEDS.LOCKUP_DYNAMIC.TREASURY, SEEDS.LOCKUP_DYNAMIC.COUNTER);
+};
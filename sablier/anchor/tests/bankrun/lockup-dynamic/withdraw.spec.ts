@@ -0,0 +1,153 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { SEGMENT_EXPONENT_SCALE, TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Dynamic Stream - Withdraw Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Dynamic Stream - Withdraw - Happy Flow', () => {
+		it('should withdraw the amount streamed by completed and ongoing segments', async () => {
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				segments: [
+					{ amount: 500, milestone: startTime + 50 },
+					{ amount: 500, milestone: startTime + 100 },
+				],
+			});
+
+			// The first segment is complete and the second one is halfway through
+			timeTravelTo(startTime + 75, banksClient, context);
+
+			const withdrawnAmount = 750;
+			await program.methods
+				.withdrawFromLockupDynamicStream(new BN(withdrawnAmount))
+				.accounts({
+					recipient: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+
+			const treasuryBalanceAfterWithdraw = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+			expect(treasuryBalanceAfterWithdraw.toNumber()).toBe(1_000 - withdrawnAmount);
+
+			const bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey);
+			const bobBalanceAfterWithdraw = await getTokenBalanceFor(bobTokenAccount, banksClient);
+			expect(bobBalanceAfterWithdraw.toNumber()).toBe(withdrawnAmount);
+
+			const streamAfterWithdraw = await program.account.lockupDynamicStream.fetch(stream);
+			expect(streamAfterWithdraw.baseStream.amounts.withdrawn.toNumber()).toBe(withdrawnAmount);
+		});
+
+		it('should follow the exponential curve of the ongoing segment', async () => {
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				segments: [{ amount: 1_000, exponent: new BN(SEGMENT_EXPONENT_SCALE).muln(2), milestone: startTime + 100 }],
+			});
+
+			// Halfway through a quadratic segment only a quarter has been streamed
+			timeTravelTo(startTime + 50, banksClient, context);
+
+			await expect(
+				program.methods
+					.withdrawFromLockupDynamicStream(new BN(251))
+					.accounts({
+						recipient: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc()
+			).rejects.toThrow(/Withdrawal amount exceeds available balance/);
+
+			const withdrawTx = await program.methods
+				.withdrawFromLockupDynamicStream(new BN(250))
+				.accounts({
+					recipient: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+			expect(withdrawTx).toBeDefined();
+		});
+	});
+
+	describe('Lockup Dynamic Stream - Withdraw - Error Flow', () => {
+		it("should fail if the signer is not the stream's recipient", async () => {
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program);
+
+			await expect(
+				program.methods
+					.withdrawFromLockupDynamicStream(new BN(1))
+					.accounts({
+						recipient: alice.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([alice])
+					.rpc()
+			).rejects.toThrow(/Only the Stream's Recipient can withdraw from the Stream/);
+		});
+
+		it("should fail if the stream hasn't started", async () => {
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime: now() + 100,
+			});
+
+			await expect(
+				program.methods
+					.withdrawFromLockupDynamicStream(new BN(1))
+					.accounts({
+						recipient: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc()
+			).rejects.toThrow(/Stream has not started yet/);
+		});
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
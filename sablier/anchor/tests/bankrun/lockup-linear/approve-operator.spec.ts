@@ -0,0 +1,128 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Approve Operator Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const approveOperator = async (recipient: Keypair, stream: PublicKey, operator: PublicKey) => {
+		return program.methods
+			.approveLockupLinearStreamOperator(operator)
+			.accounts({
+				recipient: recipient.publicKey,
+				recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+				stream,
+			})
+			.signers([recipient])
+			.rpc();
+	};
+
+	const withdrawTo = async (signer: Keypair, stream: PublicKey, treasuryTokenAccount: PublicKey, amount: number) => {
+		return program.methods
+			.withdrawFromLockupLinearStreamTo(new BN(amount))
+			.accounts({
+				destinationTokenAccount: aliceTokenAccount,
+				recipient: bob.publicKey,
+				recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+				signer: signer.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([signer])
+			.rpc();
+	};
+
+	describe('Lockup Linear Stream - Approve Operator - Happy Flow', () => {
+		it(
+			'should let the approved operator withdraw on behalf of the recipient',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				// Bob lets Alice, acting as his custodian, withdraw for him
+				await approveOperator(bob, stream, alice.publicKey);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.operatorApproval?.operator.toBase58()).toBe(alice.publicKey.toBase58());
+				expect(streamData.operatorApproval?.approver.toBase58()).toBe(bob.publicKey.toBase58());
+
+				await timeTravelTo(startTime + 50, banksClient, context);
+
+				const aliceBalanceBeforeWithdraw = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				await withdrawTo(alice, stream, treasuryTokenAccount, 500);
+
+				const aliceBalanceAfterWithdraw = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterWithdraw.toNumber()).toBe(aliceBalanceBeforeWithdraw.toNumber() + 500);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Approve Operator - Error Flow', () => {
+		it(
+			'should fail if the signer does not hold the stream NFT',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(approveOperator(alice, stream, alice.publicKey)).rejects.toThrow(
+					/Only the Stream's Recipient can approve or revoke an operator/
+				);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should not let an unapproved signer withdraw to another destination',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				await timeTravelTo(startTime + 50, banksClient, context);
+
+				await expect(withdrawTo(alice, stream, treasuryTokenAccount, 500)).rejects.toThrow(
+					/Only the Stream's Recipient can withdraw from the Stream/
+				);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
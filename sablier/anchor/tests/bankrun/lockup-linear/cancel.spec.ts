@@ -38,7 +38,7 @@ describe('Lockup Linear Stream - Cancel Test', () => {
 				const aliceInitialBalance = await getTokenBalanceFor(aliceTokenAccount, banksClient);
 				console.log('Alice Initial Balance: ', aliceInitialBalance.toNumber());
 
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program, {
 					startTime,
 					endTime: startTime + 60,
@@ -98,7 +98,7 @@ describe('Lockup Linear Stream - Cancel Test', () => {
 				const aliceInitialBalance = await getTokenBalanceFor(aliceTokenAccount, banksClient);
 				console.log('Alice Initial Balance: ', aliceInitialBalance.toNumber());
 
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program, {
 					startTime,
 					endTime: startTime + 60,
@@ -151,7 +151,7 @@ describe('Lockup Linear Stream - Cancel Test', () => {
 		it(
 			"should fail if the sender is not the stream's creator",
 			async () => {
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program);
 
 				// Attempt cancellation as Bob
@@ -178,7 +178,7 @@ describe('Lockup Linear Stream - Cancel Test', () => {
 		it(
 			'should fail if the stream is already canceled',
 			async () => {
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program);
 
 				// Cancel once
@@ -223,7 +223,7 @@ describe('Lockup Linear Stream - Cancel Test', () => {
 		it(
 			'should fail if the stream is not cancelable',
 			async () => {
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program, {
 					isCancelable: false,
 				});
@@ -251,7 +251,7 @@ describe('Lockup Linear Stream - Cancel Test', () => {
 			async () => {
 				const startTime = now() + 5;
 
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program, {
 					startTime,
 					endTime: startTime + 5, // Ends quickly
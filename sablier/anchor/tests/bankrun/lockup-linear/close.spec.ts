@@ -0,0 +1,199 @@
+import { Program } from '@coral-xyz/anchor';
+import { createTransferInstruction, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js';
+import { BankrunProvider } from 'anchor-bankrun';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+
+import { Sablier } from '@project/anchor';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Close Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+	let provider: BankrunProvider;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program, provider } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const withdrawMax = async (stream: PublicKey, treasuryTokenAccount: PublicKey) => {
+		await program.methods
+			.withdrawMaxFromLockupLinearStream()
+			.accounts({
+				recipient: bob.publicKey,
+				recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+				signer: bob.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([bob])
+			.rpc();
+	};
+
+	const close = (sender: Keypair, stream: PublicKey, treasuryTokenAccount: PublicKey) => {
+		return program.methods
+			.closeLockupLinearStream()
+			.accounts({
+				sender: sender.publicKey,
+				rentPayer: alice.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([sender])
+			.rpc();
+	};
+
+	describe('Lockup Linear Stream - Close - Happy Flow', () => {
+		it(
+			'should close a fully withdrawn stream and return the rent to the sender',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				// Withdraw everything once the stream has ended
+				timeTravelTo(startTime + 101, banksClient, context);
+				await withdrawMax(stream, treasuryTokenAccount);
+
+				const streamRent = (await banksClient.getAccount(stream))!.lamports;
+				const treasuryRent = (await banksClient.getAccount(treasuryTokenAccount))!.lamports;
+				const aliceBalanceBeforeClose = await banksClient.getBalance(alice.publicKey);
+
+				await close(alice, stream, treasuryTokenAccount);
+
+				expect(await banksClient.getAccount(stream)).toBeNull();
+				expect(await banksClient.getAccount(treasuryTokenAccount)).toBeNull();
+
+				// Alice gets both rents back, minus the transaction fee
+				const aliceBalanceAfterClose = await banksClient.getBalance(alice.publicKey);
+				expect(Number(aliceBalanceAfterClose - aliceBalanceBeforeClose)).toBeGreaterThan(streamRent + treasuryRent - 10_000);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should close a canceled stream once the recipient withdrew the rest',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				timeTravelTo(startTime + 50, banksClient, context);
+
+				await program.methods
+					.cancelLockupLinearStream()
+					.accounts({
+						sender: alice.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([alice])
+					.rpc();
+
+				await withdrawMax(stream, treasuryTokenAccount);
+				await close(alice, stream, treasuryTokenAccount);
+
+				expect(await banksClient.getAccount(stream)).toBeNull();
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should sweep the tokens donated to the treasury to the sender',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				timeTravelTo(startTime + 101, banksClient, context);
+				await withdrawMax(stream, treasuryTokenAccount);
+
+				// Someone sends tokens straight to the depleted stream's treasury
+				const donationTx = new Transaction().add(
+					createTransferInstruction(aliceTokenAccount, treasuryTokenAccount, alice.publicKey, 50)
+				);
+				await provider.sendAndConfirm!(donationTx, [alice]);
+				const aliceBalanceBeforeClose = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				await close(alice, stream, treasuryTokenAccount);
+
+				expect(await banksClient.getAccount(treasuryTokenAccount)).toBeNull();
+				const aliceBalanceAfterClose = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterClose.toNumber()).toBe(aliceBalanceBeforeClose.toNumber() + 50);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Close - Error Flow', () => {
+		it(
+			'should fail if the stream still holds funds',
+			async () => {
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(close(alice, stream, treasuryTokenAccount)).rejects.toThrow(
+					/Stream still holds funds that have not been withdrawn or refunded/
+				);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				timeTravelTo(startTime + 101, banksClient, context);
+				await withdrawMax(stream, treasuryTokenAccount);
+
+				await expect(close(bob, stream, treasuryTokenAccount)).rejects.toThrow(/Only the Stream's Creator can close the Stream/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
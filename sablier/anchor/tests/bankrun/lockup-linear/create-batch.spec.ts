@@ -0,0 +1,179 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAccount, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
+import { BankrunProvider } from 'anchor-bankrun';
+import { BanksClient } from 'solana-bankrun';
+import { getTokenBalanceFor } from '../bankrun-utils';
+import { STREAM_NAME, TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup } from './setup';
+import { getBatchEntryAccounts, getStreamCounterIndex } from './utils';
+
+describe('Lockup Linear Stream - Create Batch Test', () => {
+	let banksClient: BanksClient;
+	let program: Program<Sablier>;
+	let provider: BankrunProvider;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, tokenMint, program, provider } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const getEntry = (recipient: PublicKey, amount: number) => {
+		const startTime = now() + 60;
+
+		return {
+			name: STREAM_NAME,
+			recipient,
+			amount: new BN(amount),
+			startTime: new BN(startTime),
+			cliffTime: new BN(startTime + 1800),
+			endTime: new BN(startTime + 3600),
+			isCancelable: true,
+			isTransferable: true,
+		};
+	};
+
+	describe('Lockup Linear Stream - Create Batch - Happy Flow', () => {
+		it(
+			'should create one stream per entry',
+			async () => {
+				const carol = Keypair.generate();
+				const entries = [getEntry(bob.publicKey, 1_000), getEntry(carol.publicKey, 2_000)];
+
+				const firstIndex = await getStreamCounterIndex(program, alice.publicKey);
+				const remainingAccounts = entries.flatMap((entry, i) =>
+					getBatchEntryAccounts(program, alice.publicKey, entry.recipient, firstIndex + i)
+				);
+
+				const aliceBalanceBeforeCreation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				await program.methods
+					.createLockupLinearStreamsBatch(entries)
+					.accounts({
+						sender: alice.publicKey,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+					})
+					.remainingAccounts(remainingAccounts)
+					.signers([alice])
+					.rpc();
+
+				// The counter moved once per entry
+				const lastIndex = await getStreamCounterIndex(program, alice.publicKey);
+				expect(lastIndex).toBe(firstIndex + entries.length);
+
+				const aliceBalanceAfterCreation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterCreation.toNumber()).toBe(aliceBalanceBeforeCreation.toNumber() - 3_000);
+
+				for (const [i, entry] of entries.entries()) {
+					const [stream, treasury, nftMint, , recipientNftTokenAccount] = remainingAccounts.slice(i * 5, i * 5 + 5);
+
+					const streamData = await program.account.lockupLinearStream.fetch(stream.pubkey);
+					expect(streamData.baseStream.index.toNumber()).toBe(firstIndex + i);
+					expect(streamData.baseStream.recipient.toBase58()).toBe(entry.recipient.toBase58());
+					expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(entry.amount.toNumber());
+					expect(streamData.nftMint.toBase58()).toBe(nftMint.pubkey.toBase58());
+
+					const treasuryBalance = await getTokenBalanceFor(treasury.pubkey, banksClient);
+					expect(treasuryBalance.toNumber()).toBe(entry.amount.toNumber());
+
+					const nftTokenAccount = await getAccount(
+						provider.connection,
+						recipientNftTokenAccount.pubkey,
+						undefined,
+						TOKEN_2022_PROGRAM_ID
+					);
+					expect(nftTokenAccount.amount).toBe(BigInt(1));
+				}
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should create streams whose accounts were pre-funded by someone else',
+			async () => {
+				const entries = [getEntry(bob.publicKey, 1_000)];
+				const firstIndex = await getStreamCounterIndex(program, alice.publicKey);
+				const remainingAccounts = getBatchEntryAccounts(program, alice.publicKey, bob.publicKey, firstIndex);
+				const [stream, treasury, nftMint] = remainingAccounts;
+
+				// Bob sends lamports to the predictable stream, treasury and NFT mint addresses beforehand
+				const fundTx = new Transaction().add(
+					...[stream, treasury, nftMint].map((account) =>
+						SystemProgram.transfer({ fromPubkey: bob.publicKey, toPubkey: account.pubkey, lamports: 1_000_000 })
+					)
+				);
+				await provider.sendAndConfirm!(fundTx, [bob]);
+
+				await program.methods
+					.createLockupLinearStreamsBatch(entries)
+					.accounts({
+						sender: alice.publicKey,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+					})
+					.remainingAccounts(remainingAccounts)
+					.signers([alice])
+					.rpc();
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream.pubkey);
+				expect(streamData.baseStream.index.toNumber()).toBe(firstIndex);
+
+				const treasuryBalance = await getTokenBalanceFor(treasury.pubkey, banksClient);
+				expect(treasuryBalance.toNumber()).toBe(1_000);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Create Batch - Error Flow', () => {
+		it(
+			'should fail if the remaining accounts do not match the entries',
+			async () => {
+				const entries = [getEntry(bob.publicKey, 1_000), getEntry(bob.publicKey, 1_000)];
+				const firstIndex = await getStreamCounterIndex(program, alice.publicKey);
+
+				// Only pass the accounts of the first entry
+				await expect(
+					program.methods
+						.createLockupLinearStreamsBatch(entries)
+						.accounts({
+							sender: alice.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.remainingAccounts(getBatchEntryAccounts(program, alice.publicKey, bob.publicKey, firstIndex))
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Remaining accounts do not match the batch entries/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the batch is empty',
+			async () => {
+				await expect(
+					program.methods
+						.createLockupLinearStreamsBatch([])
+						.accounts({
+							sender: alice.publicKey,
+							tokenMint,
+							tokenProgram: TOKEN_PROGRAM_ID,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Batch must contain at least one stream/);
+			},
+			TIMEOUT
+		);
+	});
+});
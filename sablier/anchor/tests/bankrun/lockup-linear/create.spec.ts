@@ -1,21 +1,46 @@
 import { Program } from '@coral-xyz/anchor';
+import {
+	AccountState,
+	createInitializePermanentDelegateInstruction,
+	createInitializeTransferFeeConfigInstruction,
+	ExtensionType,
+	getAccount,
+	getMint,
+	getTokenMetadata,
+	TOKEN_2022_PROGRAM_ID,
+	TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
 import { Keypair, PublicKey } from '@solana/web3.js';
+import { BankrunProvider } from 'anchor-bankrun';
+import { BanksClient } from 'solana-bankrun';
+import { createAssociatedTokenAccount, mintTo } from 'spl-token-bankrun';
 
 import { Sablier } from '@project/anchor';
+import { createToken2022Mint, getTokenBalanceFor } from '../bankrun-utils';
 import { STREAM_NAME, TIMEOUT } from '../constants';
 import { now } from '../stream-utils';
 import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getStreamCounterIndex, getTreasuryTokenAccount } from './utils';
 
 describe('Lockup Linear Stream - Create Test', () => {
+	let banksClient: BanksClient;
 	let program: Program<Sablier>;
+	let provider: BankrunProvider;
 
 	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
 	let bob: Keypair;
 
 	let tokenMint: PublicKey;
+	let brokerTokenAccount: PublicKey;
 
 	beforeAll(async () => {
-		({ alice, bob, tokenMint, program } = await beforeAllSetup());
+		({ alice, aliceTokenAccount, banksClient, bob, tokenMint, program, provider } = await beforeAllSetup());
+
+		const broker = Keypair.generate();
+		// @ts-expect-error - Type error in spl-token-bankrun dependency
+		brokerTokenAccount = await createAssociatedTokenAccount(banksClient, alice, tokenMint, broker.publicKey);
 	}, TIMEOUT);
 
 	describe('Lockup Linear Stream - Create - Happy Flow', () => {
@@ -39,7 +64,7 @@ describe('Lockup Linear Stream - Create Test', () => {
 				// Verify that all fields in the stream are properly populated
 				const streamData = await program.account.lockupLinearStream.fetch(stream);
 
-				expect(streamData.baseStream.id).toBe('LL-0');
+				expect(streamData.baseStream.index.toNumber()).toBe(0);
 
 				expect(streamData.baseStream.sender.toBase58()).toBe(alice.publicKey.toBase58());
 				expect(streamData.baseStream.recipient.toBase58()).toBe(bob.publicKey.toBase58());
@@ -60,6 +85,154 @@ describe('Lockup Linear Stream - Create Test', () => {
 			},
 			TIMEOUT
 		);
+
+		it(
+			'should mint the stream NFT to the recipient',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				const nftMint = await getMint(provider.connection, streamData.nftMint, undefined, TOKEN_2022_PROGRAM_ID);
+
+				// The NFT has a fixed supply of 1 and no decimals
+				expect(nftMint.decimals).toBe(0);
+				expect(Number(nftMint.supply)).toBe(1);
+				expect(nftMint.mintAuthority).toBeNull();
+
+				// The NFT carries its own metadata, named after the stream
+				const metadata = await getTokenMetadata(provider.connection, streamData.nftMint, undefined, TOKEN_2022_PROGRAM_ID);
+				expect(metadata!.name).toBe(STREAM_NAME);
+				expect(metadata!.symbol).toBe('SAB-LL');
+
+				const nftTokenAccount = await getAccount(
+					provider.connection,
+					await getNftTokenAccount(program, stream, bob.publicKey),
+					undefined,
+					TOKEN_2022_PROGRAM_ID
+				);
+				expect(Number(nftTokenAccount.amount)).toBe(1);
+				expect(nftTokenAccount.state).toBe(AccountState.Initialized);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should freeze the stream NFT if the stream is not transferable',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program, { isTransferable: false });
+
+				const nftTokenAccount = await getAccount(
+					provider.connection,
+					await getNftTokenAccount(program, stream, bob.publicKey),
+					undefined,
+					TOKEN_2022_PROGRAM_ID
+				);
+				expect(Number(nftTokenAccount.amount)).toBe(1);
+				expect(nftTokenAccount.state).toBe(AccountState.Frozen);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should transfer the broker fee to the broker out of the deposit',
+			async () => {
+				const aliceBalanceBefore = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					amount: 1_000,
+					brokerFeeBps: 250, // 2.5%
+					brokerTokenAccount,
+				});
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(975);
+
+				const brokerBalance = await getTokenBalanceFor(brokerTokenAccount, banksClient);
+				expect(brokerBalance.toNumber()).toBe(25);
+
+				// The sender pays the full amount, broker fee included
+				const aliceBalanceAfter = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfter.toNumber()).toBe(aliceBalanceBefore.toNumber() - 1_000);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should count streams separately for each sender',
+			async () => {
+				// Fund Bob so that he can create a stream of his own
+				// @ts-expect-error - Type error in spl-token-bankrun dependency
+				const bobTokenAccount = await createAssociatedTokenAccount(banksClient, alice, tokenMint, bob.publicKey);
+				// @ts-expect-error - Type error in spl-token-bankrun dependency
+				await mintTo(banksClient, alice, tokenMint, bobTokenAccount, alice, 1_000);
+
+				const aliceStreamIndex = await getStreamCounterIndex(program, alice.publicKey);
+				expect(aliceStreamIndex).toBeGreaterThan(0);
+
+				// Bob's first stream starts his own counter, regardless of Alice's streams
+				const [stream] = await createStream(bob, alice, tokenMint, program);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.index.toNumber()).toBe(0);
+				expect(streamData.baseStream.sender.toBase58()).toBe(bob.publicKey.toBase58());
+
+				expect(await getStreamCounterIndex(program, bob.publicKey)).toBe(1);
+				expect(await getStreamCounterIndex(program, alice.publicKey)).toBe(aliceStreamIndex);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should store the start and cliff unlock amounts',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					amount: 1_000,
+					startUnlock: 100,
+					cliffUnlock: 150,
+				});
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.startUnlock.toNumber()).toBe(100);
+				expect(streamData.cliffUnlock.toNumber()).toBe(150);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should record the amount received net of the Token-2022 transfer fee',
+			async () => {
+				// 1% transfer fee
+				const { mint } = await createToken2022Mint(
+					provider,
+					alice,
+					[ExtensionType.TransferFeeConfig],
+					(mint) => [
+						createInitializeTransferFeeConfigInstruction(
+							mint,
+							alice.publicKey,
+							alice.publicKey,
+							100,
+							BigInt(1_000_000),
+							TOKEN_2022_PROGRAM_ID
+						),
+					],
+					1_000_000
+				);
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, mint, program, {
+					amount: 1_000,
+					tokenProgram: TOKEN_2022_PROGRAM_ID,
+				});
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(990);
+
+				const treasuryBalance = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+				expect(treasuryBalance.toNumber()).toBe(990);
+			},
+			TIMEOUT
+		);
 	});
 
 	describe('Lockup Linear Stream - Create - Error Flow', () => {
@@ -118,5 +291,80 @@ describe('Lockup Linear Stream - Create Test', () => {
 			},
 			TIMEOUT
 		);
+
+		it(
+			'should fail if the broker fee exceeds the maximum',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						brokerFeeBps: 1_001,
+						brokerTokenAccount,
+					})
+				).rejects.toThrow(/Broker fee exceeds the maximum allowed/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if a broker fee is charged without a broker token account',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						brokerFeeBps: 100,
+					})
+				).rejects.toThrow(/A broker token account is required to charge a broker fee/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the unlock amounts exceed the deposit',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						amount: 1_000,
+						startUnlock: 600,
+						cliffUnlock: 401,
+					})
+				).rejects.toThrow(/Unlock amounts must not exceed the deposited amount/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if a cliff unlock is set without a cliff',
+			async () => {
+				const startTime = now() + 60;
+
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						startTime,
+						cliffTime: startTime, // No cliff
+						cliffUnlock: 100,
+					})
+				).rejects.toThrow(/A cliff unlock requires a cliff time after the start time/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the token mint has a permanent delegate',
+			async () => {
+				const { mint } = await createToken2022Mint(
+					provider,
+					alice,
+					[ExtensionType.PermanentDelegate],
+					(mint) => [createInitializePermanentDelegateInstruction(mint, alice.publicKey, TOKEN_2022_PROGRAM_ID)],
+					1_000_000
+				);
+
+				await expect(
+					createStream(alice, bob, mint, program, {
+						tokenProgram: TOKEN_2022_PROGRAM_ID,
+					})
+				).rejects.toThrow(/Token mint has an extension that streams do not support/);
+			},
+			TIMEOUT
+		);
 	});
 });
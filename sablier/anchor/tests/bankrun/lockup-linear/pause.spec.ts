@@ -0,0 +1,109 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Pause Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const pause = (sender: Keypair, stream: PublicKey) => {
+		return program.methods.pauseLockupLinearStream().accounts({ sender: sender.publicKey, stream }).signers([sender]).rpc();
+	};
+
+	describe('Lockup Linear Stream - Pause - Happy Flow', () => {
+		it(
+			'should freeze the streamed amount',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				await timeTravelTo(startTime + 30, banksClient, context);
+				await pause(alice, stream);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.pausedTime?.toNumber()).toBe(startTime + 30);
+
+				// Only what was streamed before the pause can be withdrawn
+				await timeTravelTo(startTime + 80, banksClient, context);
+				await program.methods
+					.withdrawMaxFromLockupLinearStream()
+					.accounts({
+						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc();
+
+				const streamAfterWithdraw = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamAfterWithdraw.baseStream.amounts.withdrawn.toNumber()).toBe(300);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Pause - Error Flow', () => {
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(pause(bob, stream)).rejects.toThrow(/Only the Stream's Creator can pause or resume the Stream/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is not cancelable',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program, { isCancelable: false });
+
+				await expect(pause(alice, stream)).rejects.toThrow(/Stream is not cancelable/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is already paused',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+				await pause(alice, stream);
+
+				await expect(pause(alice, stream)).rejects.toThrow(/Stream is paused/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
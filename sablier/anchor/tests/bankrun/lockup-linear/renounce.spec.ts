@@ -76,7 +76,7 @@ describe('Lockup Linear Stream - Renounce Test', () => {
 		it(
 			'should fail if the stream is already canceled',
 			async () => {
-				const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 				const [stream] = await createStream(alice, bob, tokenMint, program);
 
 				// Send the cancelStream transaction
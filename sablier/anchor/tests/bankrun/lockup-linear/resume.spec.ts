@@ -0,0 +1,88 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Lockup Linear Stream - Resume Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const pause = (stream: PublicKey) => {
+		return program.methods.pauseLockupLinearStream().accounts({ sender: alice.publicKey, stream }).signers([alice]).rpc();
+	};
+
+	const resume = (sender: Keypair, stream: PublicKey) => {
+		return program.methods.resumeLockupLinearStream().accounts({ sender: sender.publicKey, stream }).signers([sender]).rpc();
+	};
+
+	describe('Lockup Linear Stream - Resume - Happy Flow', () => {
+		it(
+			'should push the schedule back by the paused duration',
+			async () => {
+				const startTime = now() + 5;
+
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime + 50,
+					endTime: startTime + 100,
+				});
+
+				await timeTravelTo(startTime + 20, banksClient, context);
+				await pause(stream);
+
+				await timeTravelTo(startTime + 60, banksClient, context);
+				await resume(alice, stream);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.pausedTime).toBeNull();
+				expect(streamData.pausedDuration.toNumber()).toBe(40);
+				expect(streamData.baseStream.startTime.toNumber()).toBe(startTime + 40);
+				expect(streamData.cliffTime.toNumber()).toBe(startTime + 90);
+				expect(streamData.baseStream.endTime.toNumber()).toBe(startTime + 140);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Resume - Error Flow', () => {
+		it(
+			'should fail if the stream is not paused',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(resume(alice, stream)).rejects.toThrow(/Stream is not paused/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+				await pause(stream);
+
+				await expect(resume(bob, stream)).rejects.toThrow(/Only the Stream's Creator can pause or resume the Stream/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
@@ -0,0 +1,68 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { TIMEOUT } from '../constants';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Revoke Operator Test', () => {
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, bob, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const revokeOperator = async (stream: PublicKey) => {
+		return program.methods
+			.revokeLockupLinearStreamOperator()
+			.accounts({
+				recipient: bob.publicKey,
+				recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+				stream,
+			})
+			.signers([bob])
+			.rpc();
+	};
+
+	describe('Lockup Linear Stream - Revoke Operator - Happy Flow', () => {
+		it(
+			'should remove the approved operator',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await program.methods
+					.approveLockupLinearStreamOperator(alice.publicKey)
+					.accounts({
+						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						stream,
+					})
+					.signers([bob])
+					.rpc();
+
+				await revokeOperator(stream);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.operatorApproval).toBeNull();
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Revoke Operator - Error Flow', () => {
+		it(
+			'should fail if no operator is approved',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(revokeOperator(stream)).rejects.toThrow(/Stream has no approved operator/);
+			},
+			TIMEOUT
+		);
+	});
+});
@@ -4,7 +4,7 @@ import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { Keypair, PublicKey } from '@solana/web3.js';
 import { SEEDS, STREAM_NAME } from '../constants';
 import { environmentSetup, now } from '../stream-utils';
-import { getStreamCounterIndex } from './utils';
+import { getStreamAddress, getStreamCounterIndex } from './utils';
 
 export interface CreateLinearLockupStreamOptions {
 	streamName?: string;
@@ -14,6 +14,11 @@ export interface CreateLinearLockupStreamOptions {
 	amount?: number;
 	isCancelable?: boolean;
 	isTransferable?: boolean;
+	brokerFeeBps?: number;
+	brokerTokenAccount?: PublicKey | null;
+	startUnlock?: number | null;
+	cliffUnlock?: number | null;
+	tokenProgram?: PublicKey;
 }
 
 export const beforeAllSetup = async () => {
@@ -36,27 +41,36 @@ export const createStream = async (
 		amount = 1_000, // Default amount: 1K tokens
 		isCancelable = true,
 		isTransferable = true,
+		brokerFeeBps = 0,
+		brokerTokenAccount = null,
+		startUnlock = null,
+		cliffUnlock = null,
+		tokenProgram = TOKEN_PROGRAM_ID,
 	} = options;
 
 	// Get the stream counter index for the stream to be created
-	const streamCounterIndex = await getStreamCounterIndex(program);
+	const streamCounterIndex = await getStreamCounterIndex(program, sender.publicKey);
 
 	// Send the createStream transaction
 	const createStreamTx = await program.methods
 		.createLockupLinearStream(
 			streamName,
-			recipient.publicKey,
 			new BN(amount),
 			new BN(startTime),
 			new BN(endTime),
 			new BN(cliffTime),
 			isCancelable,
-			isTransferable
+			isTransferable,
+			brokerFeeBps,
+			startUnlock === null ? null : new BN(startUnlock),
+			cliffUnlock === null ? null : new BN(cliffUnlock)
 		)
 		.accounts({
 			sender: sender.publicKey,
+			recipient: recipient.publicKey,
 			tokenMint,
-			tokenProgram: TOKEN_PROGRAM_ID,
+			tokenProgram,
+			brokerTokenAccount,
 		})
 		.signers([sender])
 		.rpc();
@@ -65,8 +79,5 @@ export const createStream = async (
 	expect(createStreamTx).toBeDefined();
 
 	// Derive the PDA for the newly created stream
-	return PublicKey.findProgramAddressSync(
-		[Buffer.from(SEEDS.LOCKUP_LINEAR.STREAM), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
-		program.programId
-	);
+	return getStreamAddress(program, sender.publicKey, streamCounterIndex);
 };
@@ -0,0 +1,143 @@
+import { Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { createAssociatedTokenAccount, mintTo } from 'spl-token-bankrun';
+
+import { Sablier } from '@project/anchor';
+import { SEEDS, TIMEOUT } from '../constants';
+import { getLegacyStreamAddress, migrateStream, setLegacyStream } from '../migration/utils';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getStreamAddress, getStreamCounterIndex } from './utils';
+
+describe('Lockup Linear Stream - Stream Counter Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const getStreamCounterAddress = (sender: PublicKey) => {
+		return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.LOCKUP_LINEAR.COUNTER), sender.toBuffer()], program.programId);
+	};
+
+	// Funds a new sender that has never created a stream
+	const createSender = async () => {
+		const sender = Keypair.generate();
+		context.setAccount(sender.publicKey, {
+			lamports: 10_000_000_000,
+			data: Buffer.alloc(0),
+			owner: SystemProgram.programId,
+			executable: false,
+		});
+
+		// @ts-expect-error - Type error in spl-token-bankrun dependency
+		const senderTokenAccount = await createAssociatedTokenAccount(banksClient, alice, tokenMint, sender.publicKey);
+		// @ts-expect-error - Type error in spl-token-bankrun dependency
+		await mintTo(banksClient, alice, tokenMint, senderTokenAccount, alice, 1_000_000);
+
+		return sender;
+	};
+
+	describe('Lockup Linear Stream - Stream Counter - Happy Flow', () => {
+		it(
+			"should create the sender's counter along with their first stream",
+			async () => {
+				const charlie = await createSender();
+				const [streamCounter] = getStreamCounterAddress(charlie.publicKey);
+				expect(await program.account.streamCounter.fetchNullable(streamCounter)).toBeNull();
+
+				const [stream] = await createStream(charlie, bob, tokenMint, program);
+
+				const streamCounterData = await program.account.streamCounter.fetch(streamCounter);
+				expect(streamCounterData.authority.toBase58()).toBe(charlie.publicKey.toBase58());
+				expect(streamCounterData.streamIndex.toNumber()).toBe(1);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.index.toNumber()).toBe(0);
+				expect(stream.toBase58()).toBe(getStreamAddress(program, charlie.publicKey, 0)[0].toBase58());
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should increment the index for every stream of the sender',
+			async () => {
+				const charlie = await createSender();
+
+				const [firstStream] = await createStream(charlie, bob, tokenMint, program);
+				const [secondStream] = await createStream(charlie, bob, tokenMint, program);
+
+				const firstStreamData = await program.account.lockupLinearStream.fetch(firstStream);
+				const secondStreamData = await program.account.lockupLinearStream.fetch(secondStream);
+				expect(firstStreamData.baseStream.index.toNumber()).toBe(0);
+				expect(secondStreamData.baseStream.index.toNumber()).toBe(1);
+				expect(await getStreamCounterIndex(program, charlie.publicKey)).toBe(2);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should keep a separate counter for every sender',
+			async () => {
+				const charlie = await createSender();
+				const dave = await createSender();
+
+				const [charlieStream] = await createStream(charlie, bob, tokenMint, program);
+				const [daveStream] = await createStream(dave, bob, tokenMint, program);
+
+				// Both streams have the same index, but their addresses are derived from their senders
+				const charlieStreamData = await program.account.lockupLinearStream.fetch(charlieStream);
+				const daveStreamData = await program.account.lockupLinearStream.fetch(daveStream);
+				expect(charlieStreamData.baseStream.index.toNumber()).toBe(0);
+				expect(daveStreamData.baseStream.index.toNumber()).toBe(0);
+				expect(charlieStream.toBase58()).not.toBe(daveStream.toBase58());
+
+				expect(await getStreamCounterIndex(program, charlie.publicKey)).toBe(1);
+				expect(await getStreamCounterIndex(program, dave.publicKey)).toBe(1);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should keep migrated streams at their legacy address without using the counter',
+			async () => {
+				const legacyIndex = await getStreamCounterIndex(program, alice.publicKey);
+				const { stream: legacyStream, legacyTreasury } = await setLegacyStream(context, program, {
+					index: legacyIndex,
+					sender: alice.publicKey,
+					recipient: bob.publicKey,
+					tokenMint,
+					deposited: 1_000,
+					withdrawn: 0,
+					startTime: now() - 3600,
+					endTime: now() - 60,
+				});
+
+				await migrateStream(program, alice, bob.publicKey, legacyStream, legacyTreasury, tokenMint);
+
+				const legacyStreamData = await program.account.lockupLinearStream.fetch(legacyStream);
+				expect(legacyStreamData.hasLegacyAddress).toBe(true);
+				expect(legacyStreamData.baseStream.index.toNumber()).toBe(legacyIndex);
+				expect(legacyStream.toBase58()).toBe(getLegacyStreamAddress(program, legacyIndex)[0].toBase58());
+				expect(await getStreamCounterIndex(program, alice.publicKey)).toBe(legacyIndex);
+
+				// A new stream with the same index does not collide with the migrated one
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.hasLegacyAddress).toBe(false);
+				expect(streamData.baseStream.index.toNumber()).toBe(legacyIndex);
+				expect(stream.toBase58()).not.toBe(legacyStream.toBase58());
+				expect(await getStreamCounterIndex(program, alice.publicKey)).toBe(legacyIndex + 1);
+			},
+			TIMEOUT
+		);
+	});
+});
@@ -0,0 +1,150 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { createAssociatedTokenAccount, mintTo } from 'spl-token-bankrun';
+
+import { Sablier } from '@project/anchor';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Top Up Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const topUp = (sender: Keypair, stream: PublicKey, treasuryTokenAccount: PublicKey, amount: number, mode: object) => {
+		return program.methods
+			.topUpLockupLinearStream(new BN(amount), mode as any)
+			.accounts({
+				sender: sender.publicKey,
+				stream,
+				tokenMint,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				treasuryTokenAccount,
+			})
+			.signers([sender])
+			.rpc();
+	};
+
+	describe('Lockup Linear Stream - Top Up - Happy Flow', () => {
+		it(
+			'should extend the end time at the same rate',
+			async () => {
+				const startTime = now() + 60;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 1_000,
+					amount: 1_000,
+				});
+
+				const aliceBalanceBeforeTopUp = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+
+				await topUp(alice, stream, treasuryTokenAccount, 500, { extendEndTime: {} });
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(1_500);
+				expect(streamData.baseStream.startTime.toNumber()).toBe(startTime);
+				expect(streamData.baseStream.endTime.toNumber()).toBe(startTime + 1_500);
+
+				const treasuryBalance = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+				expect(treasuryBalance.toNumber()).toBe(1_500);
+
+				const aliceBalanceAfterTopUp = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+				expect(aliceBalanceAfterTopUp.toNumber()).toBe(aliceBalanceBeforeTopUp.toNumber() - 500);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should increase the rate over the remaining period',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+					amount: 1_000,
+				});
+
+				// Half of the stream has been streamed at this point
+				timeTravelTo(startTime + 50, banksClient, context);
+
+				await topUp(alice, stream, treasuryTokenAccount, 500, { increaseRate: {} });
+
+				// The other half plus the top-up is streamed over the last 50 seconds
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(1_500);
+				expect(streamData.baseStream.startTime.toNumber()).toBe(startTime + 25);
+				expect(streamData.baseStream.endTime.toNumber()).toBe(startTime + 100);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Top Up - Error Flow', () => {
+		it(
+			'should fail if the signer is not the sender',
+			async () => {
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				// Fund Bob so that only his lack of authority can fail the top-up
+				// @ts-expect-error - Type error in spl-token-bankrun dependency
+				const bobTokenAccount = await createAssociatedTokenAccount(banksClient, alice, tokenMint, bob.publicKey);
+				// @ts-expect-error - Type error in spl-token-bankrun dependency
+				await mintTo(banksClient, alice, tokenMint, bobTokenAccount, alice, 500);
+
+				await expect(topUp(bob, stream, treasuryTokenAccount, 500, { extendEndTime: {} })).rejects.toThrow(
+					/Only the Stream's Creator can top up the Stream/
+				);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail after the end time has passed',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				timeTravelTo(startTime + 101, banksClient, context);
+
+				await expect(topUp(alice, stream, treasuryTokenAccount, 500, { extendEndTime: {} })).rejects.toThrow(
+					/Stream cannot be topped up after the end time has passed/
+				);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
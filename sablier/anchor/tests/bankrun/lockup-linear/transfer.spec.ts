@@ -0,0 +1,156 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+
+import { Sablier } from '@project/anchor';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Transfer Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	const charlie = Keypair.generate();
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Linear Stream - Transfer - Happy Flow', () => {
+		it(
+			'should transfer a transferable stream and its NFT to a new recipient',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				// Transfer the stream from Bob to Charlie
+				const transferStreamTx = await program.methods
+					.transferLockupLinearStream()
+					.accounts({
+						recipient: bob.publicKey,
+						newRecipient: charlie.publicKey,
+						stream,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					})
+					.signers([bob])
+					.rpc();
+				expect(transferStreamTx).toBeDefined();
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.recipient.toBase58()).toBe(charlie.publicKey.toBase58());
+				expect(streamData.baseStream.sender.toBase58()).toBe(alice.publicKey.toBase58());
+
+				// The NFT moved along with the stream
+				const bobNftBalance = await getTokenBalanceFor(await getNftTokenAccount(program, stream, bob.publicKey), banksClient);
+				expect(bobNftBalance.toNumber()).toBe(0);
+
+				const charlieNftBalance = await getTokenBalanceFor(
+					await getNftTokenAccount(program, stream, charlie.publicKey),
+					banksClient
+				);
+				expect(charlieNftBalance.toNumber()).toBe(1);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should move the withdrawal right along with the NFT',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+				});
+
+				await program.methods
+					.transferLockupLinearStream()
+					.accounts({
+						recipient: bob.publicKey,
+						newRecipient: charlie.publicKey,
+						stream,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					})
+					.signers([bob])
+					.rpc();
+
+				timeTravelTo(startTime + 50, banksClient, context);
+
+				// Bob no longer holds the NFT
+				await expect(
+					program.methods
+						.withdrawFromLockupLinearStream(new BN(1))
+						.accounts({
+							recipient: bob.publicKey,
+							recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+							stream,
+							tokenMint,
+								treasuryTokenAccount,
+						})
+						.signers([bob])
+						.rpc()
+				).rejects.toThrow(/Only the Stream's Recipient can withdraw from the Stream/);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Linear Stream - Transfer - Error Flow', () => {
+		it(
+			'should fail if the signer does not hold the stream NFT',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(
+					program.methods
+						.transferLockupLinearStream()
+						.accounts({
+							recipient: alice.publicKey,
+							newRecipient: charlie.publicKey,
+							stream,
+							recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+							})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Only the Stream's Recipient can transfer the Stream/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is not transferable',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program, { isTransferable: false });
+
+				await expect(
+					program.methods
+						.transferLockupLinearStream()
+						.accounts({
+							recipient: bob.publicKey,
+							newRecipient: charlie.publicKey,
+							stream,
+							recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+							})
+						.signers([bob])
+						.rpc()
+				).rejects.toThrow(/Stream is not transferable/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
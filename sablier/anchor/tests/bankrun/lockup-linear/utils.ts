@@ -1,16 +1,60 @@
-import { Program } from '@coral-xyz/anchor';
+import { BN, Program } from '@coral-xyz/anchor';
 import { Sablier } from '@project/anchor';
-import { PublicKey } from '@solana/web3.js';
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { AccountMeta, PublicKey } from '@solana/web3.js';
 import { SEEDS } from '../constants';
-import { getStreamCounterIndexWithSeed, getTreasuryTokenAccountWithSeeds } from '../stream-utils';
+import { getStreamCounterIndexWithSeed } from '../stream-utils';
 
-export const getStreamCounterIndex = async (program: Program<Sablier>) => {
-	return getStreamCounterIndexWithSeed(program, SEEDS.LOCKUP_LINEAR.COUNTER);
+export const getStreamCounterIndex = async (program: Program<Sablier>, sender: PublicKey) => {
+	return getStreamCounterIndexWithSeed(program, SEEDS.LOCKUP_LINEAR.COUNTER, sender);
 };
 
-//! Must be used before creating the stream since it gets the Stream Counter Index
+export const getStreamAddress = (program: Program<Sablier>, sender: PublicKey, streamCounterIndex: number) => {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.LOCKUP_LINEAR.STREAM), sender.toBuffer(), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
+		program.programId
+	);
+};
+
+//! Must be used before creating the stream since it gets the sender's Stream Counter Index
 //! which is used TO CREATE the stream
 //! Calling this after creating the stream will result in it using a future counter index
-export const getTreasuryTokenAccount = async (tokenMint: PublicKey, program: Program<Sablier>) => {
-	return getTreasuryTokenAccountWithSeeds(tokenMint, program, SEEDS.LOCKUP_LINEAR.TREASURY, SEEDS.LOCKUP_LINEAR.COUNTER);
+export const getTreasuryTokenAccount = async (program: Program<Sablier>, sender: PublicKey) => {
+	const [stream] = getStreamAddress(program, sender, await getStreamCounterIndex(program, sender));
+
+	return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), stream.toBuffer()], program.programId);
+};
+
+export const getNftTokenAccount = async (program: Program<Sablier>, stream: PublicKey, owner: PublicKey) => {
+	const { nftMint } = await program.account.lockupLinearStream.fetch(stream);
+
+	return getAssociatedTokenAddressSync(nftMint, owner, true, TOKEN_2022_PROGRAM_ID);
+};
+
+// Derives the remaining accounts expected by the batch creation for the sender's stream at the given counter index:
+// [stream, treasury, nft mint, recipient, recipient nft token account]
+export const getBatchEntryAccounts = (
+	program: Program<Sablier>,
+	sender: PublicKey,
+	recipient: PublicKey,
+	streamCounterIndex: number
+): AccountMeta[] => {
+	const [stream] = getStreamAddress(program, sender, streamCounterIndex);
+	const [treasury] = PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), stream.toBuffer()],
+		program.programId
+	);
+	const [nftMint] = PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.LOCKUP_LINEAR.NFT_MINT), stream.toBuffer()],
+		program.programId
+	);
+	const recipientNftTokenAccount = getAssociatedTokenAddressSync(nftMint, recipient, true, TOKEN_2022_PROGRAM_ID);
+
+	return [
+		{ pubkey: stream, isSigner: false, isWritable: true },
+		{ pubkey: treasury, isSigner: false, isWritable: true },
+		{ pubkey: nftMint, isSigner: false, isWritable: true },
+		{ pubkey: recipient, isSigner: false, isWritable: false },
+		{ pubkey: recipientNftTokenAccount, isSigner: false, isWritable: true },
+	];
 };
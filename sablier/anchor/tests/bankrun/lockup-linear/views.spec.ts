@@ -0,0 +1,102 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Views Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const getViews = async (stream: PublicKey) => {
+		const [streamed, withdrawable, refundable, status] = await Promise.all([
+			program.methods.streamedAmountOf().accounts({ stream }).view(),
+			program.methods.withdrawableAmountOf().accounts({ stream }).view(),
+			program.methods.refundableAmountOf().accounts({ stream }).view(),
+			program.methods.statusOf().accounts({ stream }).view(),
+		]);
+
+		return {
+			streamed: streamed.toNumber(),
+			withdrawable: withdrawable.toNumber(),
+			refundable: refundable.toNumber(),
+			status: Object.keys(status)[0],
+		};
+	};
+
+	describe('Lockup Linear Stream - Views - Happy Flow', () => {
+		it(
+			'should return the amounts and status at the current time',
+			async () => {
+				const startTime = now() + 5;
+
+				const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+					amount: 1_000,
+				});
+
+				await timeTravelTo(startTime - 1, banksClient, context);
+				expect(await getViews(stream)).toEqual({ streamed: 0, withdrawable: 0, refundable: 1_000, status: 'pending' });
+
+				await timeTravelTo(startTime + 25, banksClient, context);
+				expect(await getViews(stream)).toEqual({ streamed: 250, withdrawable: 250, refundable: 750, status: 'streaming' });
+
+				await program.methods
+					.cancelLockupLinearStream()
+					.accounts({
+						sender: alice.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([alice])
+					.rpc();
+
+				expect(await getViews(stream)).toEqual({ streamed: 250, withdrawable: 250, refundable: 0, status: 'canceled' });
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should report a fully streamed stream as settled',
+			async () => {
+				const startTime = now() + 5;
+
+				const [stream] = await createStream(alice, bob, tokenMint, program, {
+					startTime,
+					cliffTime: startTime,
+					endTime: startTime + 100,
+					amount: 1_000,
+				});
+
+				await timeTravelTo(startTime + 100, banksClient, context);
+				expect(await getViews(stream)).toEqual({ streamed: 1_000, withdrawable: 1_000, refundable: 0, status: 'settled' });
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
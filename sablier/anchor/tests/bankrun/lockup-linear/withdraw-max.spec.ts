@@ -0,0 +1,105 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Withdraw Max Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Linear Stream - Withdraw Max - Happy Flow', () => {
+		it('should withdraw the full withdrawable amount', async () => {
+			const amount = 1_000;
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				cliffTime: startTime, // No cliff
+				endTime: startTime + 100,
+				amount,
+			});
+
+			const bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey);
+
+			// The stream should be 40% elapsed at this point
+			timeTravelTo(startTime + 40, banksClient, context);
+
+			await program.methods
+				.withdrawMaxFromLockupLinearStream()
+				.accounts({
+					recipient: bob.publicKey,
+					recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					signer: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+
+			const treasuryBalanceAfterWithdraw = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+			expect(treasuryBalanceAfterWithdraw.toNumber()).toBe(600);
+
+			const bobBalanceAfterWithdraw = await getTokenBalanceFor(bobTokenAccount, banksClient);
+			expect(bobBalanceAfterWithdraw.toNumber()).toBeGreaterThanOrEqual(400);
+
+			const streamAfterWithdraw = await program.account.lockupLinearStream.fetch(stream);
+			expect(streamAfterWithdraw.baseStream.amounts.withdrawn.toNumber()).toBe(400);
+		});
+	});
+
+	describe('Lockup Linear Stream - Withdraw Max - Error Flow', () => {
+		it('should fail if there is nothing to withdraw', async () => {
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				cliffTime: startTime + 50,
+				endTime: startTime + 100,
+			});
+
+			// Go right before the cliff's end
+			timeTravelTo(startTime + 49, banksClient, context);
+
+			await expect(
+				program.methods
+					.withdrawMaxFromLockupLinearStream()
+					.accounts({
+						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc()
+			).rejects.toThrow(/There is nothing to withdraw from the Stream/);
+		});
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
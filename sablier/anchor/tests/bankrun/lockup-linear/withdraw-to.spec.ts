@@ -0,0 +1,98 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Linear Stream - Withdraw To Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let aliceTokenAccount: PublicKey;
+
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, aliceTokenAccount, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Linear Stream - Withdraw To - Happy Flow', () => {
+		it('should withdraw to the given destination token account', async () => {
+			const amount = 1_000;
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				cliffTime: startTime, // No cliff
+				endTime: startTime + 100,
+				amount,
+			});
+
+			// The stream should be 50% elapsed at this point
+			timeTravelTo(startTime + 50, banksClient, context);
+
+			// Bob sends the withdrawn tokens to Alice's token account
+			const aliceBalanceBeforeWithdraw = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+			await program.methods
+				.withdrawFromLockupLinearStreamTo(new BN(500))
+				.accounts({
+					destinationTokenAccount: aliceTokenAccount,
+					recipient: bob.publicKey,
+					recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					signer: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+
+			const aliceBalanceAfterWithdraw = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+			expect(aliceBalanceAfterWithdraw.toNumber()).toBe(aliceBalanceBeforeWithdraw.toNumber() + 500);
+
+			const streamAfterWithdraw = await program.account.lockupLinearStream.fetch(stream);
+			expect(streamAfterWithdraw.baseStream.amounts.withdrawn.toNumber()).toBe(500);
+		});
+	});
+
+	describe('Lockup Linear Stream - Withdraw To - Error Flow', () => {
+		it("should fail if the signer is not the stream's recipient", async () => {
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program);
+
+			// Attempt to withdraw as Alice into Alice's token account
+			await expect(
+				program.methods
+					.withdrawFromLockupLinearStreamTo(new BN(1))
+					.accounts({
+						destinationTokenAccount: aliceTokenAccount,
+						recipient: alice.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: alice.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([alice])
+					.rpc()
+			).rejects.toThrow(/Only the Stream's Recipient can withdraw from the Stream/);
+		});
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
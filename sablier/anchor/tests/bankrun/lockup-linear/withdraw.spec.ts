@@ -7,7 +7,7 @@ import { getTokenBalanceFor, timeTravelFor, timeTravelTo } from '../bankrun-util
 import { TIMEOUT } from '../constants';
 import { now } from '../stream-utils';
 import { beforeAllSetup, createStream } from './setup';
-import { getTreasuryTokenAccount } from './utils';
+import { getNftTokenAccount, getTreasuryTokenAccount } from './utils';
 
 describe('Lockup Linear Stream - Withdraw Test', () => {
 	let banksClient: BanksClient;
@@ -35,7 +35,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			const aliceInitialBalance = await getTokenBalanceFor(aliceTokenAccount, banksClient);
 			console.log('Alice Initial Balance: ', aliceInitialBalance.toNumber());
 
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime,
 				cliffTime: startTime, // No cliff
@@ -58,6 +58,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 				.withdrawFromLockupLinearStream(new BN(withdrawnAmount))
 				.accounts({
 					recipient: bob.publicKey,
+					recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					signer: bob.publicKey,
 					stream,
 					tokenMint,
 					tokenProgram: TOKEN_PROGRAM_ID,
@@ -85,20 +87,188 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			expect(streamAfterWithdraw.baseStream.amounts.refunded.toNumber()).toBe(0);
 			expect(streamAfterWithdraw.baseStream.amounts.withdrawn.toNumber()).toBe(withdrawnAmount);
 		});
+
+		it('should let the recipient withdraw their remaining share after cancelation', async () => {
+			const amount = 1_000;
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				cliffTime: startTime, // No cliff
+				endTime: startTime + 100,
+				amount,
+			});
+			const recipientNftTokenAccount = await getNftTokenAccount(program, stream, bob.publicKey);
+			bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey);
+
+			// Withdraw the first quarter of the stream
+			timeTravelTo(startTime + 25, banksClient, context);
+			await program.methods
+				.withdrawFromLockupLinearStream(new BN(250))
+				.accounts({
+					recipient: bob.publicKey,
+					recipientNftTokenAccount,
+					signer: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+
+			// Cancel halfway through, which refunds the unstreamed half to Alice
+			timeTravelTo(startTime + 50, banksClient, context);
+			const aliceBalanceBeforeCancelation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+			await program.methods
+				.cancelLockupLinearStream()
+				.accounts({
+					sender: alice.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([alice])
+				.rpc();
+
+			const aliceBalanceAfterCancelation = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+			expect(aliceBalanceAfterCancelation.toNumber()).toBe(aliceBalanceBeforeCancelation.toNumber() + 500);
+
+			// The streamed but not yet withdrawn amount stays in the treasury
+			const treasuryBalanceAfterCancelation = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+			expect(treasuryBalanceAfterCancelation.toNumber()).toBe(250);
+
+			// Bob can still withdraw the remaining share, even after the end time
+			timeTravelTo(startTime + 200, banksClient, context);
+			const bobBalanceBeforeWithdrawal = await getTokenBalanceFor(bobTokenAccount, banksClient);
+			await program.methods
+				.withdrawFromLockupLinearStream(new BN(250))
+				.accounts({
+					recipient: bob.publicKey,
+					recipientNftTokenAccount,
+					signer: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+
+			const bobBalanceAfterWithdrawal = await getTokenBalanceFor(bobTokenAccount, banksClient);
+			expect(bobBalanceAfterWithdrawal.toNumber()).toBe(bobBalanceBeforeWithdrawal.toNumber() + 250);
+
+			const treasuryBalanceAfterWithdrawal = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+			expect(treasuryBalanceAfterWithdrawal.toNumber()).toBe(0);
+
+			const streamData = await program.account.lockupLinearStream.fetch(stream);
+			expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(amount);
+			expect(streamData.baseStream.amounts.refunded.toNumber()).toBe(500);
+			expect(streamData.baseStream.amounts.withdrawn.toNumber()).toBe(500);
+		});
+
+		it('should let any signer withdraw on behalf of the recipient', async () => {
+			const amount = 1_000;
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				cliffTime: startTime, // No cliff
+				endTime: startTime + 100,
+				amount,
+			});
+			bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey);
+
+			// The stream should be 50% elapsed at this point
+			timeTravelTo(startTime + 50, banksClient, context);
+
+			const aliceBalanceBeforeWithdraw = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+			const bobBalanceBeforeWithdraw = await getTokenBalanceFor(bobTokenAccount, banksClient);
+
+			// Alice acts as a keeper and pushes the vested tokens to Bob
+			await program.methods
+				.withdrawFromLockupLinearStream(new BN(500))
+				.accounts({
+					recipient: bob.publicKey,
+					recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					signer: alice.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([alice])
+				.rpc();
+
+			// The funds went to Bob, not to the signer
+			const aliceBalanceAfterWithdraw = await getTokenBalanceFor(aliceTokenAccount, banksClient);
+			expect(aliceBalanceAfterWithdraw.toNumber()).toBe(aliceBalanceBeforeWithdraw.toNumber());
+
+			const bobBalanceAfterWithdraw = await getTokenBalanceFor(bobTokenAccount, banksClient);
+			expect(bobBalanceAfterWithdraw.toNumber()).toBe(bobBalanceBeforeWithdraw.toNumber() + 500);
+		});
+
+		it('should withdraw the start unlock before the cliff', async () => {
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				cliffTime: startTime + 50,
+				endTime: startTime + 100,
+				amount: 1_000,
+				startUnlock: 200,
+				cliffUnlock: 300,
+			});
+
+			// Only the start unlock is available before the cliff
+			await timeTravelTo(startTime + 10, banksClient, context);
+
+			const recipientNftTokenAccount = await getNftTokenAccount(program, stream, bob.publicKey);
+			const withdraw = (amount: number) =>
+				program.methods
+					.withdrawFromLockupLinearStream(new BN(amount))
+					.accounts({
+						recipient: bob.publicKey,
+						recipientNftTokenAccount,
+						signer: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc();
+
+			await expect(withdraw(201)).rejects.toThrow(/Withdrawal amount exceeds available balance/);
+			await withdraw(200);
+
+			// At the cliff, the cliff unlock is added to half of the linearly streamed 500 tokens
+			await timeTravelTo(startTime + 50, banksClient, context);
+			await withdraw(550);
+
+			const streamData = await program.account.lockupLinearStream.fetch(stream);
+			expect(streamData.baseStream.amounts.withdrawn.toNumber()).toBe(750);
+		});
 	});
 
 	describe('Lockup Linear Stream - Withdraw - Error Flow', () => {
-		it("should fail if the signer is not the stream's recipient", async () => {
+		it('should fail if the recipient does not hold the stream NFT', async () => {
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program);
 
-			// Attempt to withdraw as Alice
+			// Attempt to redirect the withdrawal to Alice
 			await expect(
 				program.methods
 					.withdrawFromLockupLinearStream(new BN(1))
 					.accounts({
 						recipient: alice.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: alice.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -111,7 +281,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 
 		it('should fail if the amount is zero', async () => {
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program);
 
 			// Attempt to withdraw zero
@@ -120,6 +290,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(0))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -132,7 +304,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 
 		it("should fail if the stream hasn't started", async () => {
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime: now() + 100,
 			});
@@ -143,6 +315,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(1))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -159,7 +333,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			const endTime = startTime + 100;
 
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime,
 				cliffTime,
@@ -189,6 +363,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(1))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -205,7 +381,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			const endTime = startTime + 100;
 
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime,
 				cliffTime,
@@ -220,6 +396,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 				.withdrawFromLockupLinearStream(new BN(1_000))
 				.accounts({
 					recipient: bob.publicKey,
+					recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					signer: bob.publicKey,
 					stream,
 					tokenMint,
 					tokenProgram: TOKEN_PROGRAM_ID,
@@ -235,6 +413,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(1))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -251,7 +431,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			const endTime = startTime + 100;
 
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime,
 				cliffTime,
@@ -283,6 +463,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 				.withdrawFromLockupLinearStream(new BN(500))
 				.accounts({
 					recipient: bob.publicKey,
+					recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+					signer: bob.publicKey,
 					stream,
 					tokenMint,
 					tokenProgram: TOKEN_PROGRAM_ID,
@@ -309,6 +491,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(1))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -323,7 +507,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			const startTime = now() + 5;
 
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime,
 				cliffTime: startTime + 5,
@@ -338,6 +522,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(1))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -350,7 +536,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 
 		it('should fail if the amount exceeds total balance', async () => {
 			// Create Stream
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program);
 
 			// Go way after the stream's end
@@ -362,6 +548,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(1_001))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
@@ -376,7 +564,7 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 			// Create Stream
 			const startTime = now() + 5;
 
-			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(program, alice.publicKey);
 			const [stream] = await createStream(alice, bob, tokenMint, program, {
 				startTime,
 				cliffTime: startTime + 50,
@@ -392,6 +580,8 @@ describe('Lockup Linear Stream - Withdraw Test', () => {
 					.withdrawFromLockupLinearStream(new BN(801))
 					.accounts({
 						recipient: bob.publicKey,
+						recipientNftTokenAccount: await getNftTokenAccount(program, stream, bob.publicKey),
+						signer: bob.publicKey,
 						stream,
 						tokenMint,
 						tokenProgram: TOKEN_PROGRAM_ID,
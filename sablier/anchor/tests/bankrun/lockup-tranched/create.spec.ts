@@ -0,0 +1,111 @@
+import { Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+
+import { Sablier } from '@project/anchor';
+import { STREAM_NAME, TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Lockup Tranched Stream - Create Test', () => {
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, bob, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Tranched Stream - Create - Happy Flow', () => {
+		it(
+			'should create a lockup tranched stream',
+			async () => {
+				// Create a lockup tranched stream
+				const startTime = now() + 60;
+				const options = {
+					streamName: STREAM_NAME,
+					startTime,
+					tranches: [
+						{ amount: 400, timestamp: startTime + 1800 },
+						{ amount: 600, timestamp: startTime + 3600 },
+					],
+					isCancelable: true,
+					isTransferable: true,
+				};
+
+				const [stream] = await createStream(alice, bob, tokenMint, program, options);
+
+				// Verify that all fields in the stream are properly populated
+				const streamData = await program.account.lockupTranchedStream.fetch(stream);
+
+				expect(streamData.baseStream.index.toNumber()).toBe(0);
+
+				expect(streamData.baseStream.sender.toBase58()).toBe(alice.publicKey.toBase58());
+				expect(streamData.baseStream.recipient.toBase58()).toBe(bob.publicKey.toBase58());
+
+				expect(streamData.baseStream.tokenMint.toBase58()).toBe(tokenMint.toBase58());
+
+				expect(streamData.baseStream.amounts.deposited.toNumber()).toBe(1_000);
+				expect(streamData.baseStream.amounts.refunded.toNumber()).toBe(0);
+				expect(streamData.baseStream.amounts.withdrawn.toNumber()).toBe(0);
+
+				// The stream ends when the last tranche unlocks
+				expect(streamData.baseStream.startTime.toNumber()).toBe(options.startTime);
+				expect(streamData.baseStream.endTime.toNumber()).toBe(startTime + 3600);
+
+				expect(streamData.tranches.length).toBe(2);
+				expect(streamData.tranches[0].amount.toNumber()).toBe(400);
+				expect(streamData.tranches[1].timestamp.toNumber()).toBe(startTime + 3600);
+
+				expect(streamData.baseStream.isCancelable).toBe(options.isCancelable);
+				expect(streamData.baseStream.isCanceled).toBe(false); // Streams are not canceled by default
+				expect(streamData.baseStream.isTransferable).toBe(options.isTransferable);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Tranched Stream - Create - Error Flow', () => {
+		it(
+			'should fail if there are no tranches',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						tranches: [],
+						amount: 1_000,
+					})
+				).rejects.toThrow(/Tranche count must be between 1 and the maximum allowed/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if a tranche unlocks before the start time',
+			async () => {
+				const startTime = now() + 60;
+
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						startTime,
+						tranches: [{ amount: 1_000, timestamp: startTime }], // Unlocks at the start time
+					})
+				).rejects.toThrow(/Tranche timestamps must be strictly increasing and after the start time/);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the tranche amounts do not add up to the deposited amount',
+			async () => {
+				await expect(
+					createStream(alice, bob, tokenMint, program, {
+						amount: 999,
+					})
+				).rejects.toThrow(/Tranche amounts must add up to the deposited amount/);
+			},
+			TIMEOUT
+		);
+	});
+});
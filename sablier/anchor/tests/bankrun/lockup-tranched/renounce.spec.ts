@@ -0,0 +1,84 @@
+import { Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+
+import { Sablier } from '@project/anchor';
+import { TIMEOUT } from '../constants';
+import { beforeAllSetup, createStream } from './setup';
+
+describe('Lockup Tranched Stream - Renounce Test', () => {
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, bob, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Tranched Stream - Renounce - Happy Flow', () => {
+		it(
+			'should renounce cancelability for a cancelable stream',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				// Renounce cancelability for the stream
+				const renounceCancelableStreamTx = await program.methods
+					.renounceCancelabilityLockupTranchedStream()
+					.accounts({
+						sender: alice.publicKey,
+						stream,
+					})
+					.signers([alice])
+					.rpc();
+				expect(renounceCancelableStreamTx).toBeDefined();
+
+				const streamData = await program.account.lockupTranchedStream.fetch(stream);
+				expect(streamData.baseStream.isCancelable).toBe(false);
+				expect(streamData.baseStream.isCanceled).toBe(false);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Lockup Tranched Stream - Renounce - Error Flow', () => {
+		it(
+			"should fail if the sender is not the stream's creator",
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program);
+
+				await expect(
+					program.methods
+						.renounceCancelabilityLockupTranchedStream()
+						.accounts({
+							sender: bob.publicKey,
+							stream,
+						})
+						.signers([bob])
+						.rpc()
+				).rejects.toThrow(/Only the Stream's Creator can renounce the Stream's cancelability./);
+			},
+			TIMEOUT
+		);
+
+		it(
+			'should fail if the stream is not cancelable',
+			async () => {
+				const [stream] = await createStream(alice, bob, tokenMint, program, { isCancelable: false });
+
+				await expect(
+					program.methods
+						.renounceCancelabilityLockupTranchedStream()
+						.accounts({
+							sender: alice.publicKey,
+							stream,
+						})
+						.signers([alice])
+						.rpc()
+				).rejects.toThrow(/Stream is not cancelable/);
+			},
+			TIMEOUT
+		);
+	});
+});
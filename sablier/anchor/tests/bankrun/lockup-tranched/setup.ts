@@ -0,0 +1,83 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { SEEDS, STREAM_NAME } from '../constants';
+import { getTeamLockupStreamAddress, teamLockupSetup, TeamLockupFamily } from '../lockup-utils';
+import { now } from '../stream-utils';
+import { getStreamCounterIndex } from './utils';
+
+export interface TrancheOptions {
+	amount: number;
+	timestamp: number;
+}
+
+export interface CreateLockupTranchedStreamOptions {
+	streamName?: string;
+	startTime?: number;
+	tranches?: TrancheOptions[];
+	amount?: number;
+	isCancelable?: boolean;
+	isTransferable?: boolean;
+}
+
+export const LOCKUP_TRANCHED: TeamLockupFamily = {
+	seeds: SEEDS.LOCKUP_TRANCHED,
+	initializeStreamCounter: 'initializeLockupTranchedStreamCounter',
+};
+
+export const beforeAllSetup = () => teamLockupSetup(LOCKUP_TRANCHED);
+
+export const createStream = async (
+	sender: Keypair,
+	recipient: Keypair,
+	tokenMint: PublicKey,
+	program: Program<Sablier>,
+	options: CreateLockupTranchedStreamOptions = {}
+) => {
+	// Assign defaults using destructuring
+	const {
+		streamName = STREAM_NAME,
+		startTime = now() + 60, // Start in 1 minute
+		tranches = [
+			{ amount: 250, timestamp: startTime + 900 }, // Quarter every 15 minutes
+			{ amount: 250, timestamp: startTime + 1800 },
+			{ amount: 250, timestamp: startTime + 2700 },
+			{ amount: 250, timestamp: startTime + 3600 },
+		],
+		isCancelable = true,
+		isTransferable = true,
+	} = options;
+	const amount = options.amount ?? tranches.reduce((total, tranche) => total + tranche.amount, 0);
+
+	// Get the stream counter index for the stream to be created
+	const streamCounterIndex = await getStreamCounterIndex(program);
+
+	// Send the createStream transaction
+	const createStreamTx = await program.methods
+		.createLockupTranchedStream(
+			streamName,
+			recipient.publicKey,
+			new BN(amount),
+			new BN(startTime),
+			tranches.map(({ amount, timestamp }) => ({
+				amount: new BN(amount),
+				timestamp: new BN(timestamp),
+			})),
+			isCancelable,
+			isTransferable
+		)
+		.accounts({
+			sender: sender.publicKey,
+			tokenMint,
+			tokenProgram: TOKEN_PROGRAM_ID,
+		})
+		.signers([sender])
+		.rpc();
+
+	// Ensure the transaction was successful
+	expect(createStreamTx).toBeDefined();
+
+	// Derive the PDA for the newly created stream
+	return getTeamLockupStreamAddress(LOCKUP_TRANCHED, program, streamCounterIndex);
+};
@@ -0,0 +1,134 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { TIMEOUT } from '../constants';
+import { now } from '../stream-utils';
+import { beforeAllSetup, createStream } from './setup';
+import { getTreasuryTokenAccount } from './utils';
+
+describe('Lockup Tranched Stream - Withdraw Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	describe('Lockup Tranched Stream - Withdraw - Happy Flow', () => {
+		it('should withdraw the tranches that have unlocked', async () => {
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				tranches: [
+					{ amount: 250, timestamp: startTime + 25 },
+					{ amount: 250, timestamp: startTime + 50 },
+					{ amount: 500, timestamp: startTime + 100 },
+				],
+			});
+
+			// The first two tranches have unlocked, the third one has not
+			timeTravelTo(startTime + 99, banksClient, context);
+
+			await expect(
+				program.methods
+					.withdrawFromLockupTranchedStream(new BN(501))
+					.accounts({
+						recipient: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc()
+			).rejects.toThrow(/Withdrawal amount exceeds available balance/);
+
+			const withdrawnAmount = 500;
+			await program.methods
+				.withdrawFromLockupTranchedStream(new BN(withdrawnAmount))
+				.accounts({
+					recipient: bob.publicKey,
+					stream,
+					tokenMint,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					treasuryTokenAccount,
+				})
+				.signers([bob])
+				.rpc();
+
+			const treasuryBalanceAfterWithdraw = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+			expect(treasuryBalanceAfterWithdraw.toNumber()).toBe(1_000 - withdrawnAmount);
+
+			const bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey);
+			const bobBalanceAfterWithdraw = await getTokenBalanceFor(bobTokenAccount, banksClient);
+			expect(bobBalanceAfterWithdraw.toNumber()).toBe(withdrawnAmount);
+
+			const streamAfterWithdraw = await program.account.lockupTranchedStream.fetch(stream);
+			expect(streamAfterWithdraw.baseStream.amounts.withdrawn.toNumber()).toBe(withdrawnAmount);
+		});
+	});
+
+	describe('Lockup Tranched Stream - Withdraw - Error Flow', () => {
+		it("should fail if the signer is not the stream's recipient", async () => {
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program);
+
+			await expect(
+				program.methods
+					.withdrawFromLockupTranchedStream(new BN(1))
+					.accounts({
+						recipient: alice.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([alice])
+					.rpc()
+			).rejects.toThrow(/Only the Stream's Recipient can withdraw from the Stream/);
+		});
+
+		it('should fail if no tranche has unlocked yet', async () => {
+			const startTime = now() + 5;
+
+			const [treasuryTokenAccount] = await getTreasuryTokenAccount(tokenMint, program);
+			const [stream] = await createStream(alice, bob, tokenMint, program, {
+				startTime,
+				tranches: [{ amount: 1_000, timestamp: startTime + 100 }],
+			});
+
+			// Right before the only tranche unlocks
+			timeTravelTo(startTime + 99, banksClient, context);
+
+			await expect(
+				program.methods
+					.withdrawFromLockupTranchedStream(new BN(1))
+					.accounts({
+						recipient: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc()
+			).rejects.toThrow(/Withdrawal amount exceeds available balance/);
+		});
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		timeTravelTo(now(), banksClient, context);
+	});
+});
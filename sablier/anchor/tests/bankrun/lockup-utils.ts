@@ -0,0 +1,33 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { PublicKey } from '@solana/web3.js';
+import { environmentSetup } from './stream-utils';
+
+// Lockup streams whose PDAs are derived from a global stream counter initialized by the team
+export interface TeamLockupFamily {
+	seeds: { STREAM: string; TREASURY: string; COUNTER: string };
+	initializeStreamCounter: 'initializeLockupDynamicStreamCounter' | 'initializeLockupTranchedStreamCounter';
+}
+
+export const teamLockupSetup = async (family: TeamLockupFamily) => {
+	const setup = await environmentSetup(family.seeds.COUNTER);
+
+	// Initialize the family's StreamCounter once
+	const tx = await setup.program.methods[family.initializeStreamCounter]()
+		.accounts({
+			sender: setup.teamKeypair.publicKey,
+		})
+		.signers([setup.teamKeypair])
+		.rpc();
+
+	expect(tx).toBeDefined();
+
+	return setup;
+};
+
+export const getTeamLockupStreamAddress = (family: TeamLockupFamily, program: Program<Sablier>, streamCounterIndex: number) => {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(family.seeds.STREAM), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
+		program.programId
+	);
+};
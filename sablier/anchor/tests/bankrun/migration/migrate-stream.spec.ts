@@ -0,0 +1,117 @@
+import { Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { getAccount, getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BankrunProvider } from 'anchor-bankrun';
+import { BanksClient, ProgramTestContext } from 'solana-bankrun';
+import { getTokenBalanceFor, timeTravelTo } from '../bankrun-utils';
+import { SEEDS, TIMEOUT } from '../constants';
+import { beforeAllSetup } from '../lockup-linear/setup';
+import { getNftTokenAccount } from '../lockup-linear/utils';
+import { now } from '../stream-utils';
+import { migrateStream, setLegacyStream } from './utils';
+
+describe('Migration - Migrate Lockup Linear Stream Test', () => {
+	let banksClient: BanksClient;
+	let context: ProgramTestContext;
+	let program: Program<Sablier>;
+	let provider: BankrunProvider;
+
+	let alice: Keypair;
+	let bob: Keypair;
+
+	let tokenMint: PublicKey;
+
+	beforeAll(async () => {
+		({ alice, banksClient, bob, context, tokenMint, program, provider } = await beforeAllSetup());
+	}, TIMEOUT);
+
+	const setLegacyStreamFor = (index: number, deposited: number, withdrawn: number, startTime: number, endTime: number) => {
+		return setLegacyStream(context, program, {
+			index,
+			sender: alice.publicKey,
+			recipient: bob.publicKey,
+			tokenMint,
+			deposited,
+			withdrawn,
+			startTime,
+			endTime,
+		});
+	};
+
+	const migrate = (stream: PublicKey, legacyTreasury: PublicKey) => {
+		return migrateStream(program, alice, bob.publicKey, stream, legacyTreasury, tokenMint);
+	};
+
+	describe('Migration - Migrate Lockup Linear Stream - Happy Flow', () => {
+		it(
+			'should migrate a legacy stream and withdraw from it at its legacy address',
+			async () => {
+				const startTime = now() - 3600;
+				const endTime = now() - 60;
+				const { stream, legacyTreasury } = await setLegacyStreamFor(7, 1_000, 250, startTime, endTime);
+
+				await migrate(stream, legacyTreasury);
+
+				const streamData = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamData.baseStream.index.toNumber()).toBe(7);
+				expect(streamData.hasLegacyAddress).toBe(true);
+
+				// The tokens moved to the treasury derived from the stream, and the legacy treasury is gone
+				const [treasuryTokenAccount] = PublicKey.findProgramAddressSync(
+					[Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), stream.toBuffer()],
+					program.programId
+				);
+				const treasuryBalance = await getTokenBalanceFor(treasuryTokenAccount, banksClient);
+				expect(treasuryBalance.toNumber()).toBe(750);
+				expect(await banksClient.getAccount(legacyTreasury)).toBeNull();
+
+				const recipientNftTokenAccount = await getNftTokenAccount(program, stream, bob.publicKey);
+				const nftTokenAccount = await getAccount(provider.connection, recipientNftTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+				expect(nftTokenAccount.amount).toBe(BigInt(1));
+
+				await timeTravelTo(endTime + 1, banksClient, context);
+
+				await program.methods
+					.withdrawMaxFromLockupLinearStream()
+					.accounts({
+						recipient: bob.publicKey,
+						recipientNftTokenAccount,
+						signer: bob.publicKey,
+						stream,
+						tokenMint,
+						tokenProgram: TOKEN_PROGRAM_ID,
+						treasuryTokenAccount,
+					})
+					.signers([bob])
+					.rpc();
+
+				const bobTokenAccount = getAssociatedTokenAddressSync(tokenMint, bob.publicKey, false, TOKEN_PROGRAM_ID);
+				const bobBalance = await getTokenBalanceFor(bobTokenAccount, banksClient);
+				expect(bobBalance.toNumber()).toBe(750);
+
+				const streamDataAfterWithdrawal = await program.account.lockupLinearStream.fetch(stream);
+				expect(streamDataAfterWithdrawal.baseStream.amounts.withdrawn.toNumber()).toBe(1_000);
+			},
+			TIMEOUT
+		);
+	});
+
+	describe('Migration - Migrate Lockup Linear Stream - Error Flow', () => {
+		it(
+			'should fail if the legacy treasury does not belong to the stream',
+			async () => {
+				const { stream } = await setLegacyStreamFor(8, 1_000, 0, now() - 3600, now() - 60);
+				const { legacyTreasury: otherLegacyTreasury } = await setLegacyStreamFor(9, 1_000, 0, now() - 3600, now() - 60);
+
+				await expect(migrate(stream, otherLegacyTreasury)).rejects.toThrow(/Account is not a legacy lockup stream/);
+			},
+			TIMEOUT
+		);
+	});
+
+	afterEach(async () => {
+		// Go back to present
+		await timeTravelTo(now(), banksClient, context);
+	});
+});
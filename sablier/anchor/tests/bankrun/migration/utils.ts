@@ -0,0 +1,124 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { Sablier } from '@project/anchor';
+import { ACCOUNT_SIZE, AccountLayout, AccountState, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { ProgramTestContext } from 'solana-bankrun';
+import IDL from '../../../target/idl/sablier.json';
+import { SEEDS, STREAM_NAME } from '../constants';
+
+// Size of the lockup linear stream accounts created before the migration, with ids and names of 32 bytes
+const LEGACY_STREAM_SIZE = 8 + 2 * (4 + 32) + 3 * 32 + 3 * 8 + 2 * 8 + 3 + 8;
+
+export interface LegacyStreamOptions {
+	index: number;
+	sender: PublicKey;
+	recipient: PublicKey;
+	tokenMint: PublicKey;
+	deposited: number;
+	withdrawn: number;
+	startTime: number;
+	endTime: number;
+}
+
+// Legacy streams were derived from a global counter, without the sender
+export const getLegacyStreamAddress = (program: Program<Sablier>, index: number) => {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.LOCKUP_LINEAR.STREAM), new BN(index).toArrayLike(Buffer, 'le', 8)],
+		program.programId
+	);
+};
+
+export const getLegacyTreasuryAddress = (program: Program<Sablier>, tokenMint: PublicKey, index: number) => {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), tokenMint.toBuffer(), new BN(index).toArrayLike(Buffer, 'le', 8)],
+		program.programId
+	);
+};
+
+// Writes a stream and its treasury the way the legacy program laid them out
+export const setLegacyStream = async (context: ProgramTestContext, program: Program<Sablier>, options: LegacyStreamOptions) => {
+	const { index, sender, recipient, tokenMint, deposited, withdrawn, startTime, endTime } = options;
+	const [stream] = getLegacyStreamAddress(program, index);
+	const [legacyTreasury] = getLegacyTreasuryAddress(program, tokenMint, index);
+	const rent = await context.banksClient.getRent();
+
+	const discriminator = IDL.accounts.find((account) => account.name === 'LockupLinearStream')!.discriminator;
+	const data = Buffer.alloc(LEGACY_STREAM_SIZE);
+	data.set(discriminator, 0);
+	let offset = discriminator.length;
+	for (const string of [`LL-${index}`, STREAM_NAME]) {
+		offset = data.writeUInt32LE(string.length, offset);
+		offset += data.write(string, offset);
+	}
+	for (const pubkey of [sender, recipient, tokenMint]) {
+		data.set(pubkey.toBuffer(), offset);
+		offset += 32;
+	}
+	for (const amount of [deposited, 0, withdrawn]) {
+		offset = data.writeBigUInt64LE(BigInt(amount), offset);
+	}
+	for (const time of [startTime, endTime]) {
+		offset = data.writeBigInt64LE(BigInt(time), offset);
+	}
+	// Cancelable, canceled and transferable flags
+	offset = data.writeUInt8(1, offset);
+	offset = data.writeUInt8(0, offset);
+	offset = data.writeUInt8(1, offset);
+	data.writeBigInt64LE(BigInt(startTime), offset);
+
+	context.setAccount(stream, {
+		lamports: Number(rent.minimumBalance(BigInt(LEGACY_STREAM_SIZE))),
+		data,
+		owner: program.programId,
+		executable: false,
+	});
+
+	const treasuryData = Buffer.alloc(ACCOUNT_SIZE);
+	AccountLayout.encode(
+		{
+			mint: tokenMint,
+			owner: legacyTreasury,
+			amount: BigInt(deposited - withdrawn),
+			delegateOption: 0,
+			delegate: PublicKey.default,
+			state: AccountState.Initialized,
+			isNativeOption: 0,
+			isNative: BigInt(0),
+			delegatedAmount: BigInt(0),
+			closeAuthorityOption: 0,
+			closeAuthority: PublicKey.default,
+		},
+		treasuryData
+	);
+	context.setAccount(legacyTreasury, {
+		lamports: Number(rent.minimumBalance(BigInt(ACCOUNT_SIZE))),
+		data: treasuryData,
+		owner: TOKEN_PROGRAM_ID,
+		executable: false,
+	});
+
+	return { stream, legacyTreasury };
+};
+
+export const migrateStream = (
+	program: Program<Sablier>,
+	sender: Keypair,
+	recipient: PublicKey,
+	stream: PublicKey,
+	legacyTreasury: PublicKey,
+	tokenMint: PublicKey
+) => {
+	return program.methods
+		.migrateLockupLinearStream()
+		.accounts({
+			signer: sender.publicKey,
+			stream,
+			recipient,
+			sender: sender.publicKey,
+			legacyTreasuryTokenAccount: legacyTreasury,
+			tokenMint,
+			tokenProgram: TOKEN_PROGRAM_ID,
+		})
+		.signers([sender])
+		.rpc();
+};
@@ -13,11 +13,13 @@ dotenv.config({ path: __dirname + '/../.env' });
 
 export const now = () => Math.floor(Date.now() / 1000);
 
-export const getStreamCounterIndexWithSeed = async (program: Program<Sablier>, seed: String) => {
-	const [streamCounter] = PublicKey.findProgramAddressSync([Buffer.from(seed)], program.programId);
-	const streamCounterData = await program.account.streamCounter.fetch(streamCounter);
+// Counters scoped to an authority (e.g. per-sender counters) only exist after their first stream
+export const getStreamCounterIndexWithSeed = async (program: Program<Sablier>, seed: String, authority?: PublicKey) => {
+	const seeds = authority ? [Buffer.from(seed), authority.toBuffer()] : [Buffer.from(seed)];
+	const [streamCounter] = PublicKey.findProgramAddressSync(seeds, program.programId);
+	const streamCounterData = await program.account.streamCounter.fetchNullable(streamCounter);
 
-	return streamCounterData.streamIndex.toNumber();
+	return streamCounterData ? streamCounterData.streamIndex.toNumber() : 0;
 };
 
 export const getTreasuryTokenAccountWithSeeds = async (
@@ -118,16 +120,16 @@ export const environmentSetup = async (seed: String) => {
 	// @ts-expect-error - Type error in spl-token-bankrun dependency
 	await mintTo(banksClient, alice, tokenMint, aliceTokenAccount, alice, 1_000_000_000_000);
 
-	// Initialize the LockupLinearStreamCounter once
-	const tx = await program.methods
-		.initializeLockupLinearStreamCounter()
+	// Initialize the Config once, without any protocol fee
+	const configTx = await program.methods
+		.initializeConfig(teamKeypair.publicKey, 0)
 		.accounts({
-			sender: teamKeypair.publicKey,
+			authority: teamKeypair.publicKey,
 		})
 		.signers([teamKeypair])
 		.rpc();
 
-	expect(tx).toBeDefined();
+	expect(configTx).toBeDefined();
 
 	return {
 		alice,
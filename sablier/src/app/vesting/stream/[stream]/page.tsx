@@ -1,7 +1,7 @@
 import StreamFeature from '@/components/vesting/stream/stream-feature';
 
 export default async function StreamView({ params }: { params: Promise<{ stream: string }> }) {
-	const streamId = (await params).stream;
+	const streamAddress = (await params).stream;
 
-	return <StreamFeature streamId={streamId} />;
+	return <StreamFeature streamAddress={streamAddress} />;
 }
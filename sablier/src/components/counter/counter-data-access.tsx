@@ -2,37 +2,26 @@
 
 import { getSablierProgram, getSablierProgramId } from '@project/anchor';
 import { Cluster, PublicKey } from '@solana/web3.js';
-import { useMutation, useQuery } from '@tanstack/react-query';
+import { useQuery } from '@tanstack/react-query';
 import { useMemo } from 'react';
-import { toast } from 'sonner';
 import { useCluster } from '../cluster/cluster-data-access';
 import { useAnchorProvider } from '../solana/solana-provider';
-import { useTransactionToast } from '../ui/ui-layout';
+import { getStreamCounterAddress } from '../vesting/stream/stream-data-access';
 
-export function useCounterProgram() {
+export function useCounterProgram(sender: PublicKey | null) {
 	const { cluster } = useCluster();
-	const transactionToast = useTransactionToast();
 	const provider = useAnchorProvider();
 	const programId = useMemo(() => getSablierProgramId(cluster.network as Cluster), [cluster]);
 	const program = useMemo(() => getSablierProgram(provider, programId), [provider, programId]);
 
-	const streamCounterAccounts = useQuery({
-		queryKey: ['streamCounter', 'all', { cluster }],
-		queryFn: () => program.account.streamCounter.all(),
-	});
-
-	const initializeStreamCounter = useMutation({
-		mutationKey: ['sablier', 'initialize', { cluster }],
-		mutationFn: (publicKey: PublicKey) => program.methods.initializeLockupLinearStreamCounter().accounts({ sender: publicKey }).rpc(),
-		onSuccess: (signature) => {
-			transactionToast(signature);
-			return streamCounterAccounts.refetch();
-		},
-		onError: () => toast.error('Failed to initialize account'),
+	// Each sender has their own counter, created along with their first stream
+	const streamCounterAccount = useQuery({
+		queryKey: ['streamCounter', 'fetch', { cluster, sender: sender?.toBase58() }],
+		queryFn: () => program.account.streamCounter.fetchNullable(getStreamCounterAddress(sender!, programId)),
+		enabled: !!sender,
 	});
 
 	return {
-		streamCounterAccounts,
-		initializeStreamCounter,
+		streamCounterAccount,
 	};
 }
@@ -1,21 +1,13 @@
 'use client';
 
-import { CounterCreate, CounterList } from '@/components/counter/counter-ui';
+import { CounterList } from '@/components/counter/counter-ui';
 import { useWallet } from '@solana/wallet-adapter-react';
-import { useEffect, useState } from 'react';
 import { NotConnected } from '../ui/ui-common';
 import { useCounterProgram } from './counter-data-access';
 
 export default function CounterFeature() {
 	const { publicKey } = useWallet();
-	const { streamCounterAccounts, initializeStreamCounter } = useCounterProgram();
-	const [hasAccount, setHasAccount] = useState(false);
-
-	useEffect(() => {
-		if (streamCounterAccounts?.data?.length && streamCounterAccounts?.data?.length > 0) {
-			setHasAccount(true);
-		}
-	}, [streamCounterAccounts]);
+	const { streamCounterAccount } = useCounterProgram(publicKey);
 
 	if (!publicKey) {
 		return <NotConnected />;
@@ -23,17 +15,10 @@ export default function CounterFeature() {
 
 	return (
 		<div className="flex flex-col items-center justify-center min-h-screen p-8">
-			{!hasAccount ? (
-				<CounterCreate
-					onCreate={() => initializeStreamCounter.mutateAsync(publicKey)}
-					isPending={initializeStreamCounter.isPending}
-				/>
-			) : (
-				<CounterList
-					authority={streamCounterAccounts.data?.at(0)?.account.authority.toBase58() ?? '[Public Key]'}
-					index={streamCounterAccounts.data?.at(0)?.account.streamIndex.toNumber() ?? 0}
-				/>
-			)}
+			<CounterList
+				authority={publicKey.toBase58()}
+				index={streamCounterAccount.data?.streamIndex.toNumber() ?? 0}
+			/>
 		</div>
 	);
 }
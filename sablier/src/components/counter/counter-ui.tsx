@@ -1,31 +1,10 @@
 'use client';
 
-interface CounterCreateProps {
-	onCreate: () => void;
-	isPending: boolean;
-}
-
 interface CounterListProps {
 	authority: string;
 	index: number;
 }
 
-export function CounterCreate({ onCreate, isPending }: CounterCreateProps) {
-	return (
-		<div className="text-center text-white">
-			<h1 className="text-5xl font-bold">🚀 Initialize Stream Counter 🚀</h1>
-			<p className="mt-4 text-xl">⚠️ Beware: Only authorized personnel can interact with this page! ⚠️</p>
-			<button
-				onClick={onCreate}
-				disabled={isPending}
-				className="mt-8 px-6 py-3 text-lg font-semibold bg-sablier-orange-gradient rounded-lg shadow-md disabled:opacity-50"
-			>
-				{isPending ? 'Creating...' : 'Create Counter'}
-			</button>
-		</div>
-	);
-}
-
 export function CounterList({ authority, index }: CounterListProps) {
 	return (
 		<div className="w-2/3 bg-sablier-gray p-8 rounded-lg shadow-sm shadow-sablier-dark-orange text-white">
@@ -1,21 +1,14 @@
 'use client';
 
-import { useCluster } from '@/components/cluster/cluster-data-access';
 import { SEEDS } from '@/utils/constants';
-import { toStreamCounterIndex } from '@/utils/conversion';
-import { getSablierProgramId } from '@project/anchor';
-import { Cluster, PublicKey } from '@solana/web3.js';
+import { PublicKey } from '@solana/web3.js';
 import BN from 'bn.js';
 import { useMemo } from 'react';
 import { useLockupLinearProgramAccount } from '../vesting-data-access';
 
-export function useGetStreamById(streamId: string) {
-	const { cluster } = useCluster();
-	const programId = useMemo(() => getSablierProgramId(cluster.network as Cluster), [cluster]);
-	const streamAddress = useMemo(() => {
-		return getStreamAddressById(streamId, programId);
-	}, [programId, streamId]);
-	const { streamQuery } = useLockupLinearProgramAccount({ account: streamAddress });
+export function useGetStreamByAddress(streamAddress: string) {
+	const account = useMemo(() => new PublicKey(streamAddress), [streamAddress]);
+	const { streamQuery } = useLockupLinearProgramAccount({ account });
 
 	return {
 		stream: streamQuery.data,
@@ -24,9 +17,18 @@ export function useGetStreamById(streamId: string) {
 	};
 }
 
-export function getStreamAddressById(streamId: string, programId: PublicKey) {
+// Streams are indexed per sender, so the index alone does not identify a stream
+export function getStreamAddress(sender: PublicKey, streamCounterIndex: number, programId: PublicKey) {
 	return PublicKey.findProgramAddressSync(
-		[Buffer.from(SEEDS.LOCKUP_LINEAR.STREAM), new BN(toStreamCounterIndex(streamId)).toArrayLike(Buffer, 'le', 8)],
+		[Buffer.from(SEEDS.LOCKUP_LINEAR.STREAM), sender.toBuffer(), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
 		programId
 	)[0];
 }
+
+export function getStreamCounterAddress(sender: PublicKey, programId: PublicKey) {
+	return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.LOCKUP_LINEAR.COUNTER), sender.toBuffer()], programId)[0];
+}
+
+export function getTreasuryAddress(stream: PublicKey, programId: PublicKey) {
+	return PublicKey.findProgramAddressSync([Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), stream.toBuffer()], programId)[0];
+}
@@ -3,12 +3,13 @@
 import NotFound from '@/app/not-found';
 import { NotConnected } from '@/components/ui/ui-common';
 import { useWallet } from '@solana/wallet-adapter-react';
-import { useGetStreamById } from './stream-data-access';
+import { PublicKey } from '@solana/web3.js';
+import { useGetStreamByAddress } from './stream-data-access';
 import StreamDetailsPage from './stream-ui';
 
-export default function StreamFeature({ streamId }: { streamId: string }) {
+export default function StreamFeature({ streamAddress }: { streamAddress: string }) {
 	const { publicKey } = useWallet();
-	const { stream, isLoading, error } = useGetStreamById(streamId);
+	const { stream, isLoading, error } = useGetStreamByAddress(streamAddress);
 
 	if (!publicKey) {
 		return <NotConnected />;
@@ -22,5 +23,5 @@ export default function StreamFeature({ streamId }: { streamId: string }) {
 		return <NotFound />;
 	}
 
-	return <StreamDetailsPage stream={stream} />;
+	return <StreamDetailsPage stream={stream} streamAddress={new PublicKey(streamAddress)} />;
 }
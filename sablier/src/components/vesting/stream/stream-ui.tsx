@@ -14,6 +14,7 @@ import {
 	formatStartStatus,
 	formatStreamState,
 } from '@/utils/formatting';
+import { toLockupLinearStreamId } from '@/utils/conversion';
 import {
 	addDecimals,
 	canBeWithdrawnFrom,
@@ -28,7 +29,7 @@ import { useEffect, useState } from 'react';
 import { toast } from 'sonner';
 import { useLockupLinearProgram } from '../vesting-data-access';
 
-export default function StreamDetailsPage({ stream }: { stream: LockupLinearStream }) {
+export default function StreamDetailsPage({ stream, streamAddress }: { stream: LockupLinearStream; streamAddress: PublicKey }) {
 	const { cluster } = useCluster();
 	const [_, setCurrentTime] = useState(() => Date.now());
 	const [isWithdrawDrawerOpen, setIsWithdrawDrawerOpen] = useState(false);
@@ -37,12 +38,12 @@ export default function StreamDetailsPage({ stream }: { stream: LockupLinearStre
 
 	const { cancelLockupLinearStream, renounceCancelabilityLockupLinearStream, withdrawFromLockupLinearStream } = useLockupLinearProgram();
 
-	const cancelOnClick = ({ streamId, tokenMint }: { streamId: string; tokenMint: PublicKey }) => {
-		cancelLockupLinearStream.mutateAsync({ streamId, tokenMint });
+	const cancelOnClick = (tokenMint: PublicKey) => {
+		cancelLockupLinearStream.mutateAsync({ stream: streamAddress, tokenMint });
 	};
 
-	const renounceOnClick = (streamId: string) => {
-		renounceCancelabilityLockupLinearStream.mutateAsync(streamId);
+	const renounceOnClick = () => {
+		renounceCancelabilityLockupLinearStream.mutateAsync(streamAddress);
 	};
 
 	const openWithdrawDrawer = () => {
@@ -73,7 +74,7 @@ export default function StreamDetailsPage({ stream }: { stream: LockupLinearStre
 
 		withdrawFromLockupLinearStream.mutateAsync({
 			amount: parsedAmount,
-			streamId: stream.baseStream.id,
+			stream: streamAddress,
 			tokenMint: stream.baseStream.tokenMint,
 		});
 
@@ -99,7 +100,7 @@ export default function StreamDetailsPage({ stream }: { stream: LockupLinearStre
 
 	return (
 		<div className="min-h-screen w-full text-white relative">
-			<Breadcrumb crumb={stream.baseStream.id} />
+			<Breadcrumb crumb={toLockupLinearStreamId(stream.baseStream.index.toNumber())} />
 
 			<div className="flex flex-col md:flex-row gap-6 p-6">
 				<main className="md:w-3/5 flex flex-col items-center justify-center">
@@ -295,7 +296,7 @@ function CircleDisplay({ stream }: { stream: LockupLinearStream }) {
 function StreamHeader({ stream }: { stream: LockupLinearStream }) {
 	return (
 		<div>
-			<h2 className="text-2xl font-bold">{`${stream.baseStream.name} (${stream.baseStream.id})`}</h2>
+			<h2 className="text-2xl font-bold">{`${stream.baseStream.name} (${toLockupLinearStreamId(stream.baseStream.index.toNumber())})`}</h2>
 		</div>
 	);
 }
@@ -357,8 +358,8 @@ function ActionsCard({
 	onWithdrawClick,
 }: {
 	stream: LockupLinearStream;
-	cancelOnClick: ({ streamId, tokenMint }: { streamId: string; tokenMint: PublicKey }) => void;
-	renounceOnClick: (streamId: string) => void;
+	cancelOnClick: (tokenMint: PublicKey) => void;
+	renounceOnClick: () => void;
 	onWithdrawClick: () => void;
 }) {
 	return (
@@ -370,14 +371,14 @@ function ActionsCard({
 					label="Cancel"
 					locked={formatCancelabilityStatus(stream) !== 'Yes'}
 					onClick={() => {
-						cancelOnClick({ streamId: stream.baseStream.id, tokenMint: stream.baseStream.tokenMint });
+						cancelOnClick(stream.baseStream.tokenMint);
 					}}
 				/>
 				<ActionItem
 					label="Renounce Cancelability"
 					locked={formatCancelabilityStatus(stream) !== 'Yes'}
 					onClick={() => {
-						renounceOnClick(stream.baseStream.id);
+						renounceOnClick();
 					}}
 				/>
 			</div>
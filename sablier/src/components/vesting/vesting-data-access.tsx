@@ -1,9 +1,8 @@
 'use client';
 
-import { SEEDS } from '@/utils/constants';
-import { CreateLockupLinearStreamArgs, toLockupLinearStreamId, toStreamCounterIndex } from '@/utils/conversion';
+import { CreateLockupLinearStreamArgs } from '@/utils/conversion';
 import { getSablierProgram, getSablierProgramId } from '@project/anchor';
-import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { Cluster, PublicKey } from '@solana/web3.js';
 import { useMutation, useQuery } from '@tanstack/react-query';
@@ -12,17 +11,14 @@ import { useRouter } from 'next/navigation';
 import { useMemo } from 'react';
 import { toast } from 'sonner';
 import { useCluster } from '../cluster/cluster-data-access';
-import { useCounterProgram } from '../counter/counter-data-access';
 import { useAnchorProvider } from '../solana/solana-provider';
 import { useTransactionToast } from '../ui/ui-layout';
-import { getStreamAddressById } from './stream/stream-data-access';
+import { getStreamAddress, getStreamCounterAddress, getTreasuryAddress } from './stream/stream-data-access';
 
 export function useLockupLinearProgram() {
 	const { publicKey } = useWallet();
 	const { cluster } = useCluster();
 
-	const { streamCounterAccounts } = useCounterProgram();
-
 	const router = useRouter();
 	const transactionToast = useTransactionToast();
 	const provider = useAnchorProvider();
@@ -36,33 +32,42 @@ export function useLockupLinearProgram() {
 
 	const createLockupLinearStream = useMutation({
 		mutationKey: ['lockupLinear', 'create', { cluster }],
-		mutationFn: (stream: CreateLockupLinearStreamArgs) =>
-			program.methods
+		mutationFn: async (stream: CreateLockupLinearStreamArgs) => {
+			// The sender's counter only exists after their first stream
+			const streamCounter = await program.account.streamCounter.fetchNullable(getStreamCounterAddress(publicKey!, programId));
+			const streamAddress = getStreamAddress(publicKey!, streamCounter?.streamIndex.toNumber() ?? 0, programId);
+
+			const tx = await program.methods
 				.createLockupLinearStream(
 					stream.name,
-					stream.recipient,
 					stream.amount,
 					stream.startTime,
 					stream.endTime,
 					stream.cliffTime,
 					stream.isCancelable,
-					stream.isTransferable
+					stream.isTransferable,
+					0, // No broker fee
+					null, // No start unlock
+					null // No cliff unlock
 				)
 				.accounts({
 					sender: publicKey!,
+					recipient: stream.recipient,
 					tokenMint: stream.tokenMint,
 					tokenProgram: TOKEN_2022_PROGRAM_ID,
+					brokerTokenAccount: null,
 				})
-				.rpc(),
+				.rpc();
+
+			return { tx, streamAddress };
+		},
 		onMutate: async () => {
 			await lockupLinearStreams.refetch();
 		},
-		onSuccess: (tx) => {
+		onSuccess: ({ tx, streamAddress }) => {
 			transactionToast(tx);
 			lockupLinearStreams.refetch();
-			const streamCounterIndex = streamCounterAccounts.data!.at(0)!.account.streamIndex;
-			const streamId = toLockupLinearStreamId(streamCounterIndex.toNumber());
-			router.push(`/vesting/stream/${streamId}`);
+			router.push(`/vesting/stream/${streamAddress.toBase58()}`);
 		},
 		onError: (error) => {
 			toast.error(error.message);
@@ -71,13 +76,8 @@ export function useLockupLinearProgram() {
 
 	const cancelLockupLinearStream = useMutation({
 		mutationKey: ['lockupLinear', 'cancel', { cluster }],
-		mutationFn: ({ streamId, tokenMint }: { streamId: string; tokenMint: PublicKey }) => {
-			const stream = getStreamAddressById(streamId, programId);
-			const streamCounterIndex = toStreamCounterIndex(streamId);
-			const [treasuryTokenAccount] = PublicKey.findProgramAddressSync(
-				[Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), tokenMint.toBuffer(), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
-				program.programId
-			);
+		mutationFn: ({ stream, tokenMint }: { stream: PublicKey; tokenMint: PublicKey }) => {
+			const treasuryTokenAccount = getTreasuryAddress(stream, programId);
 
 			return program.methods
 				.cancelLockupLinearStream()
@@ -104,9 +104,7 @@ export function useLockupLinearProgram() {
 
 	const renounceCancelabilityLockupLinearStream = useMutation({
 		mutationKey: ['lockupLinear', 'renounce', { cluster }],
-		mutationFn: (streamId: string) => {
-			const stream = getStreamAddressById(streamId, programId);
-
+		mutationFn: (stream: PublicKey) => {
 			return program.methods
 				.renounceCancelabilityLockupLinearStream()
 				.accounts({
@@ -129,18 +127,17 @@ export function useLockupLinearProgram() {
 
 	const withdrawFromLockupLinearStream = useMutation({
 		mutationKey: ['lockupLinear', 'withdraw', { cluster }],
-		mutationFn: ({ amount, streamId, tokenMint }: { amount: number; streamId: string; tokenMint: PublicKey }) => {
-			const stream = getStreamAddressById(streamId, programId);
-			const streamCounterIndex = toStreamCounterIndex(streamId);
-			const [treasuryTokenAccount] = PublicKey.findProgramAddressSync(
-				[Buffer.from(SEEDS.LOCKUP_LINEAR.TREASURY), tokenMint.toBuffer(), new BN(streamCounterIndex).toArrayLike(Buffer, 'le', 8)],
-				program.programId
-			);
+		mutationFn: async ({ amount, stream, tokenMint }: { amount: number; stream: PublicKey; tokenMint: PublicKey }) => {
+			const { nftMint } = await program.account.lockupLinearStream.fetch(stream);
+			const recipientNftTokenAccount = getAssociatedTokenAddressSync(nftMint, publicKey!, true, TOKEN_2022_PROGRAM_ID);
+			const treasuryTokenAccount = getTreasuryAddress(stream, programId);
 
 			return program.methods
 				.withdrawFromLockupLinearStream(new BN(amount))
 				.accounts({
 					recipient: publicKey!,
+					recipientNftTokenAccount,
+					signer: publicKey!,
 					stream,
 					tokenMint,
 					tokenProgram: TOKEN_2022_PROGRAM_ID,
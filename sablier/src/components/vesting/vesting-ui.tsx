@@ -1,5 +1,6 @@
 'use client';
 
+import { toLockupLinearStreamId } from '@/utils/conversion';
 import { getElapsedAmount } from '@/utils/math';
 import { useRouter } from 'next/navigation';
 import { useEffect, useState } from 'react';
@@ -59,7 +60,7 @@ export function TableWithTabs() {
 
 		const filtered = lockupLinearStreams.data.filter(({ account }) => {
 			const streamName = account?.baseStream?.name || '';
-			const streamId = account?.baseStream?.id || '';
+			const streamId = account?.baseStream ? toLockupLinearStreamId(account.baseStream.index.toNumber()) : '';
 			const matchesName = !searchName || streamName.toLowerCase().includes(searchName.toLowerCase());
 			const matchesId = !searchId || streamId.toLowerCase().includes(searchId.toLowerCase());
 
@@ -113,12 +114,12 @@ export function TableWithTabs() {
 						{filteredStreams.map(({ account, publicKey }) => {
 							const { baseStream } = account;
 							const streamName = baseStream?.name || 'Untitled';
-							const streamId = baseStream?.id || 'N/A';
+							const streamId = baseStream ? toLockupLinearStreamId(baseStream.index.toNumber()) : 'N/A';
 
 							return (
 								<tr
 									key={publicKey.toBase58()}
-									onClick={() => router.push(`/vesting/stream/${streamId}`)}
+									onClick={() => router.push(`/vesting/stream/${publicKey.toBase58()}`)}
 									className="border-t border-sablier-gray cursor-pointer transition-transform duration-300 hover:scale-[1.02] hover:bg-sablier-gray"
 								>
 									<td className="pl-8 pr-4 py-4">{formatStreamState(account)}</td>
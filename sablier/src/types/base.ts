@@ -2,7 +2,9 @@ import { PublicKey } from '@solana/web3.js';
 import BN from 'bn.js';
 
 export type BaseStream = {
-	id: string;
+	index: BN;
+	bump: number;
+	treasuryBump: number;
 	name: string;
 	sender: PublicKey;
 	recipient: PublicKey;
@@ -17,6 +19,7 @@ export type BaseStream = {
 
 export type Amounts = {
 	deposited: BN;
+	protocolFee: BN;
 	refunded: BN;
 	withdrawn: BN;
 };
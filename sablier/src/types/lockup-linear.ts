@@ -1,7 +1,9 @@
+import { PublicKey } from '@solana/web3.js';
 import BN from 'bn.js';
 import { BaseStream } from './base';
 
 export type LockupLinearStream = {
 	baseStream: BaseStream;
 	cliffTime: BN;
+	nftMint: PublicKey;
 };
@@ -25,10 +25,6 @@ export function toCreateLockupLinearStreamArgs(
 	};
 }
 
-export function toStreamCounterIndex(streamId: string) {
-	return streamId.split('-')[1];
-}
-
 export type CreateLockupLinearStreamArgs = {
 	name: string;
 	recipient: PublicKey;